@@ -0,0 +1,93 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{ parse_macro_input, parenthesized, token::Paren, Attribute, Data, DeriveInput, Error, Fields, LitInt, LitStr, Token };
+use syn::parse::{ Parse, ParseStream };
+
+struct Semantic {
+    name: LitStr,
+    index: u32
+}
+impl Parse for Semantic {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: LitStr = input.parse()?;
+        let index = match input.parse::<Option<Token![,]>>()? {
+            Some(_) => input.parse::<LitInt>()?.base10_parse()?,
+            None => 0
+        };
+
+        Ok(Self { name, index })
+    }
+}
+
+#[proc_macro_derive(VertexFormat, attributes(semantic))]
+pub fn derive_vertex_format(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match expand(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into()
+    }
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    if !has_repr_c(&input.attrs) {
+        return Err(Error::new_spanned(&input.ident, "VertexFormat requires #[repr(C)]"));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "VertexFormat requires named fields"))
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "VertexFormat can only be derived for structs"))
+    };
+
+    let mut elements = vec![];
+    for field in fields.iter() {
+        let ident = field.ident.as_ref().ok_or_else(|| Error::new(Span::call_site(), "unnamed field"))?;
+        let ty = &field.ty;
+        let attr = field.attrs
+            .iter()
+            .find(|attr| attr.path().is_ident("semantic"))
+            .ok_or_else(|| Error::new_spanned(ident, "missing #[semantic(\"NAME\", index)] attribute"))?;
+        let Semantic { name, index } = attr.parse_args()?;
+
+        elements.push(quote! {
+            ::edx::VertexElement {
+                semantic_name: #name,
+                semantic_index: #index,
+                format: <#ty as ::edx::VertexAttribute>::FORMAT,
+                offset: ::core::mem::offset_of!(Self, #ident) as u32
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::edx::VertexFormat for #ident #ty_generics #where_clause {
+            fn elements() -> ::std::vec::Vec<::edx::VertexElement> {
+                ::std::vec![#(#elements),*]
+            }
+        }
+    })
+}
+
+fn has_repr_c(attrs: &[Attribute]) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("repr")).any(|attr| {
+        let mut repr_c = false;
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") {
+                repr_c = true;
+            }
+            if meta.input.peek(Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+        repr_c
+    })
+}
\ No newline at end of file
@@ -0,0 +1,152 @@
+use crate::{ ScreenRect };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DisplayMode {
+    pub width: u32,
+    pub height: u32,
+    pub refresh_rate: [u32; 2],
+    pub format: u32
+}
+impl DisplayMode {
+    pub fn refresh_hz(&self) -> f32 {
+        match self.refresh_rate[1] {
+            0 => 0.0,
+            denominator => self.refresh_rate[0] as f32 / denominator as f32
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutputInfo {
+    pub device_name: String,
+    pub monitor_name: String,
+    pub desktop: ScreenRect,
+    pub attached: bool,
+    pub rotation: u32,
+    pub modes: Vec<DisplayMode>
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdapterInfo {
+    pub index: u32,
+    pub description: String,
+    pub vendor_id: u32,
+    pub device_id: u32,
+    pub subsystem_id: u32,
+    pub revision: u32,
+    pub luid: u64,
+    pub dedicated_video_memory: u64,
+    pub dedicated_system_memory: u64,
+    pub shared_system_memory: u64,
+    pub software: bool,
+    pub outputs: Vec<OutputInfo>
+}
+impl AdapterInfo {
+    pub const VENDOR_AMD: u32 = 0x1002;
+    pub const VENDOR_NVIDIA: u32 = 0x10de;
+    pub const VENDOR_INTEL: u32 = 0x8086;
+    pub const VENDOR_MICROSOFT: u32 = 0x1414;
+
+    pub fn vendor_name(&self) -> &'static str {
+        match self.vendor_id {
+            Self::VENDOR_AMD => "AMD",
+            Self::VENDOR_NVIDIA => "NVIDIA",
+            Self::VENDOR_INTEL => "Intel",
+            Self::VENDOR_MICROSOFT => "Microsoft",
+            _ => "Unknown"
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AdapterPreference {
+    Default,
+    HighPerformance,
+    MinimumPower,
+    Index(u32),
+    Vendor(u32),
+    Luid(u64)
+}
+impl AdapterPreference {
+    pub fn select(&self, adapters: &[AdapterInfo]) -> Option<usize> {
+        let hardware = || adapters.iter().enumerate().filter(|(_, adapter)| !adapter.software);
+
+        match self {
+            Self::Default => match adapters.is_empty() {
+                true => None,
+                false => Some(0)
+            },
+            Self::HighPerformance => hardware()
+                .max_by_key(|(index, adapter)| (adapter.dedicated_video_memory, usize::MAX - index))
+                .map(|(index, _)| index),
+            Self::MinimumPower => hardware()
+                .min_by_key(|(index, adapter)| (adapter.dedicated_video_memory, *index))
+                .map(|(index, _)| index),
+            Self::Index(index) => adapters.iter().position(|adapter| adapter.index == *index),
+            Self::Vendor(vendor_id) => hardware()
+                .find(|(_, adapter)| adapter.vendor_id == *vendor_id)
+                .map(|(index, _)| index),
+            Self::Luid(luid) => adapters.iter().position(|adapter| adapter.luid == *luid)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter(index: u32, vendor_id: u32, dedicated_video_memory: u64, software: bool) -> AdapterInfo {
+        AdapterInfo {
+            index,
+            description: format!("Adapter {}", index),
+            vendor_id,
+            device_id: 0,
+            subsystem_id: 0,
+            revision: 0,
+            luid: 0x1_0000_0000 + index as u64,
+            dedicated_video_memory,
+            dedicated_system_memory: 0,
+            shared_system_memory: 0,
+            software,
+            outputs: vec![]
+        }
+    }
+
+    fn adapters() -> Vec<AdapterInfo> {
+        vec![
+            adapter(0, AdapterInfo::VENDOR_INTEL, 128 << 20, false),
+            adapter(1, AdapterInfo::VENDOR_NVIDIA, 8 << 30, false),
+            adapter(2, AdapterInfo::VENDOR_AMD, 8 << 30, false),
+            adapter(3, AdapterInfo::VENDOR_MICROSOFT, 0, true)
+        ]
+    }
+
+    #[test]
+    fn selects_by_power_preference() {
+        let adapters = adapters();
+
+        assert_eq!(AdapterPreference::Default.select(&adapters), Some(0));
+        assert_eq!(AdapterPreference::HighPerformance.select(&adapters), Some(1));
+        assert_eq!(AdapterPreference::MinimumPower.select(&adapters), Some(0));
+        assert_eq!(AdapterPreference::HighPerformance.select(&adapters[3..]), None);
+        assert_eq!(AdapterPreference::MinimumPower.select(&adapters[3..]), None);
+        assert_eq!(AdapterPreference::Default.select(&[]), None);
+    }
+
+    #[test]
+    fn selects_by_identity() {
+        let adapters = adapters();
+
+        assert_eq!(AdapterPreference::Luid(0x1_0000_0002).select(&adapters), Some(2));
+        assert_eq!(AdapterPreference::Luid(0x1_0000_0003).select(&adapters), Some(3));
+        assert_eq!(AdapterPreference::Luid(7).select(&adapters), None);
+        assert_eq!(AdapterPreference::Index(1).select(&adapters[1..]), Some(0));
+        assert_eq!(AdapterPreference::Vendor(AdapterInfo::VENDOR_AMD).select(&adapters), Some(2));
+        assert_eq!(AdapterPreference::Vendor(AdapterInfo::VENDOR_MICROSOFT).select(&adapters), None);
+        assert_eq!(adapters[1].vendor_name(), "NVIDIA");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,332 @@
+use crate::{ PackedColor };
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32
+}
+impl Color {
+    pub const TRANSPARENT: Self = Self::rgba(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+    pub const GRAY: Self = Self::rgb(0.5, 0.5, 0.5);
+    pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+    pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+    pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+    pub const YELLOW: Self = Self::rgb(1.0, 1.0, 0.0);
+    pub const CYAN: Self = Self::rgb(0.0, 1.0, 1.0);
+    pub const MAGENTA: Self = Self::rgb(1.0, 0.0, 1.0);
+    pub const ORANGE: Self = Self::rgb(1.0, 0.5, 0.0);
+    pub const PURPLE: Self = Self::rgb(0.5, 0.0, 0.5);
+
+    pub const fn rgba(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::rgba(r, g, b, 1.0)
+    }
+
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::rgba(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0)
+    }
+
+    /// `rgba` is written as `0xRRGGBBAA`, the same order as `from_hex`/`to_hex`. This is not the layout of
+    /// `PackedColor`, whose `u32` reads as `0xAABBGGRR`; convert through `From` instead of reusing the raw value.
+    pub fn from_u32(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::from_rgba8(r, g, b, a)
+    }
+
+    pub fn from_rgb_u32(rgb: u32) -> Self {
+        Self::from_u32((rgb << 8) | 0xff)
+    }
+
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).ok();
+        let byte = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+
+        match hex.len() {
+            3 => Some(Self::from_rgba8(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17, 255)),
+            4 => Some(Self::from_rgba8(digit(0)? * 17, digit(1)? * 17, digit(2)? * 17, digit(3)? * 17)),
+            6 => Some(Self::from_rgba8(byte(0)?, byte(2)?, byte(4)?, 255)),
+            8 => Some(Self::from_rgba8(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+            _ => None
+        }
+    }
+
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32, alpha: f32) -> Self {
+        let chroma = value * saturation;
+        let [r, g, b] = Self::hue_to_rgb(hue, chroma);
+        let m = value - chroma;
+
+        Self::rgba(r + m, g + m, b + m, alpha)
+    }
+
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let [r, g, b] = Self::hue_to_rgb(hue, chroma);
+        let m = lightness - chroma / 2.0;
+
+        Self::rgba(r + m, g + m, b + m, alpha)
+    }
+
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        self.to_array().map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    pub fn to_u32(&self) -> u32 {
+        u32::from_be_bytes(self.to_rgba8())
+    }
+
+    pub fn to_hex(&self) -> String {
+        format!("#{:08x}", self.to_u32())
+    }
+
+    pub fn to_array(&self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    pub fn to_hsv(&self) -> [f32; 4] {
+        let (max, min, hue) = self.hue();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        [hue, saturation, max, self.a]
+    }
+
+    pub fn to_hsl(&self) -> [f32; 4] {
+        let (max, min, hue) = self.hue();
+        let lightness = (max + min) / 2.0;
+        let saturation = match lightness {
+            lightness if lightness <= 0.0 || lightness >= 1.0 => 0.0,
+            lightness => (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        [hue, saturation, lightness, self.a]
+    }
+
+    pub fn to_linear(&self) -> Self {
+        Self::rgba(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a)
+    }
+
+    pub fn to_srgb(&self) -> Self {
+        Self::rgba(linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b), self.a)
+    }
+
+    pub fn premultiply(&self) -> Self {
+        Self::rgba(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    pub fn unpremultiply(&self) -> Self {
+        if self.a == 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        Self::rgba(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    pub fn with_alpha(&self, alpha: f32) -> Self {
+        Self::rgba(self.r, self.g, self.b, alpha)
+    }
+
+    pub fn lerp(&self, other: Self, t: f32) -> Self {
+        let channel = |from: f32, to: f32| from + (to - from) * t;
+
+        Self::rgba(channel(self.r, other.r), channel(self.g, other.g), channel(self.b, other.b), channel(self.a, other.a))
+    }
+
+    pub fn mix(&self, other: Self, t: f32) -> Self {
+        self.to_linear().lerp(other.to_linear(), t).to_srgb()
+    }
+
+    fn hue(&self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+
+        let hue = match delta {
+            0.0 => 0.0,
+            _ if max == self.r => 60.0 * ((self.g - self.b) / delta).rem_euclid(6.0),
+            _ if max == self.g => 60.0 * ((self.b - self.r) / delta + 2.0),
+            _ => 60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+
+        (max, min, hue)
+    }
+
+    fn hue_to_rgb(hue: f32, chroma: f32) -> [f32; 3] {
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+
+        match sector as u32 {
+            0 => [chroma, x, 0.0],
+            1 => [x, chroma, 0.0],
+            2 => [0.0, chroma, x],
+            3 => [0.0, x, chroma],
+            4 => [x, 0.0, chroma],
+            _ => [chroma, 0.0, x]
+        }
+    }
+}
+impl From<[f32; 4]> for Color {
+    fn from(color: [f32; 4]) -> Self {
+        Self::rgba(color[0], color[1], color[2], color[3])
+    }
+}
+impl From<[f32; 3]> for Color {
+    fn from(color: [f32; 3]) -> Self {
+        Self::rgb(color[0], color[1], color[2])
+    }
+}
+impl From<u32> for Color {
+    fn from(rgba: u32) -> Self {
+        Self::from_u32(rgba)
+    }
+}
+impl From<PackedColor> for Color {
+    fn from(color: PackedColor) -> Self {
+        Self::from(color.to_f32())
+    }
+}
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> Self {
+        color.to_array()
+    }
+}
+impl From<Color> for PackedColor {
+    fn from(color: Color) -> Self {
+        Self::from_f32(color.to_array())
+    }
+}
+
+pub fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f32 = 1e-4;
+
+    fn assert_close(left: Color, right: Color) {
+        let close = left.to_array().iter().zip(right.to_array()).all(|(left, right)| (left - right).abs() < EPSILON);
+        assert!(close, "{:?} != {:?}", left, right);
+    }
+
+    #[test]
+    fn parses_hex() {
+        assert_eq!(Color::from_hex("#ff8000"), Some(Color::from_rgba8(255, 128, 0, 255)));
+        assert_eq!(Color::from_hex("ff800080"), Some(Color::from_rgba8(255, 128, 0, 128)));
+        assert_eq!(Color::from_hex("#f80"), Some(Color::from_rgba8(255, 136, 0, 255)));
+        assert_eq!(Color::from_hex("f808"), Some(Color::from_rgba8(255, 136, 0, 136)));
+        assert_eq!(Color::from_hex("#FFFFFF"), Some(Color::WHITE));
+
+        assert_eq!(Color::from_hex(""), None);
+        assert_eq!(Color::from_hex("#ff"), None);
+        assert_eq!(Color::from_hex("#ff800"), None);
+        assert_eq!(Color::from_hex("#gg8000"), None);
+        assert_eq!(Color::from_hex("#ff80\u{e9}"), None);
+        assert_eq!(Color::from_hex("+f+f+f"), None);
+
+        let color = Color::from_rgba8(18, 52, 86, 120);
+        assert_eq!(color.to_hex(), "#12345678");
+        assert_eq!(Color::from_hex(&color.to_hex()), Some(color));
+    }
+
+    #[test]
+    fn packs_channel_orders() {
+        let color = Color::from_u32(0x11223344);
+        assert_eq!(color.to_rgba8(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(color.to_u32(), 0x11223344);
+        assert_eq!(Color::from_rgb_u32(0x112233), Color::from_u32(0x112233ff));
+
+        let packed = PackedColor::from(color);
+        assert_eq!(packed, PackedColor(0x44332211));
+        assert_eq!(packed.to_rgba8(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(Color::from(packed), color);
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        assert_close(Color::from_hsv(0.0, 1.0, 1.0, 1.0), Color::RED);
+        assert_close(Color::from_hsv(120.0, 1.0, 1.0, 1.0), Color::GREEN);
+        assert_close(Color::from_hsv(-120.0, 1.0, 1.0, 0.5), Color::BLUE.with_alpha(0.5));
+        assert_close(Color::from_hsv(30.0, 1.0, 1.0, 1.0), Color::ORANGE);
+
+        for color in [Color::RED, Color::CYAN, Color::PURPLE, Color::GRAY, Color::rgba(0.2, 0.4, 0.9, 0.3), Color::rgb(0.9, 0.1, 0.5)] {
+            let [hue, saturation, value, alpha] = color.to_hsv();
+            assert_close(Color::from_hsv(hue, saturation, value, alpha), color);
+        }
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        assert_eq!(Color::GRAY.to_hsl(), [0.0, 0.0, 0.5, 1.0]);
+        assert_eq!(Color::WHITE.to_hsl(), [0.0, 0.0, 1.0, 1.0]);
+        assert_close(Color::from_hsl(300.0, 1.0, 0.25, 1.0), Color::PURPLE);
+
+        for color in [Color::YELLOW, Color::MAGENTA, Color::BLACK, Color::rgba(0.2, 0.4, 0.9, 0.3), Color::rgb(0.9, 0.1, 0.5)] {
+            let [hue, saturation, lightness, alpha] = color.to_hsl();
+            assert_close(Color::from_hsl(hue, saturation, lightness, alpha), color);
+        }
+    }
+
+    #[test]
+    fn converts_between_srgb_and_linear() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert!((srgb_to_linear(1.0) - 1.0).abs() < EPSILON);
+        assert!((srgb_to_linear(0.5) - 0.21404).abs() < EPSILON);
+        assert!((srgb_to_linear(0.04) - 0.04 / 12.92).abs() < EPSILON);
+        assert!((linear_to_srgb(0.21404) - 0.5).abs() < EPSILON);
+        assert!((linear_to_srgb(0.002) - 0.002 * 12.92).abs() < EPSILON);
+
+        for step in 0..=20 {
+            let channel = step as f32 / 20.0;
+            assert!((linear_to_srgb(srgb_to_linear(channel)) - channel).abs() < EPSILON);
+        }
+
+        let color = Color::rgba(0.5, 0.25, 1.0, 0.5);
+        assert_eq!(color.to_linear().a, 0.5);
+        assert_close(color.to_linear().to_srgb(), color);
+    }
+
+    #[test]
+    fn premultiplies_alpha() {
+        let color = Color::rgba(0.8, 0.4, 0.2, 0.5);
+        assert_close(color.premultiply(), Color::rgba(0.4, 0.2, 0.1, 0.5));
+        assert_close(color.premultiply().unpremultiply(), color);
+        assert_eq!(Color::rgba(1.0, 1.0, 1.0, 0.0).premultiply(), Color::TRANSPARENT);
+        assert_eq!(Color::rgba(0.5, 0.5, 0.5, 0.0).unpremultiply(), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn interpolates() {
+        let from = Color::rgba(0.0, 0.2, 1.0, 0.0);
+        let to = Color::rgba(1.0, 0.6, 0.0, 1.0);
+
+        assert_eq!(from.lerp(to, 0.0), from);
+        assert_eq!(from.lerp(to, 1.0), to);
+        assert_close(from.lerp(to, 0.5), Color::rgba(0.5, 0.4, 0.5, 0.5));
+        assert_close(Color::BLACK.mix(Color::WHITE, 0.5), Color::rgb(linear_to_srgb(0.5), linear_to_srgb(0.5), linear_to_srgb(0.5)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,229 @@
+use crate::{ constant_layout, Color };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BackbufferFormat {
+    Rgba8Unorm,
+    Bgra8Unorm,
+    Bgrx8Unorm,
+    Rgba8UnormSrgb,
+    Bgra8UnormSrgb,
+    Bgrx8UnormSrgb,
+    Rgb10A2Unorm,
+    Rgba16Float,
+    Other(u32)
+}
+impl BackbufferFormat {
+    pub fn from_dxgi(format: u32) -> Self {
+        match format {
+            27 | 28 => Self::Rgba8Unorm,
+            29 => Self::Rgba8UnormSrgb,
+            87 | 90 => Self::Bgra8Unorm,
+            91 => Self::Bgra8UnormSrgb,
+            88 | 92 => Self::Bgrx8Unorm,
+            93 => Self::Bgrx8UnormSrgb,
+            23 | 24 => Self::Rgb10A2Unorm,
+            9 | 10 => Self::Rgba16Float,
+            format => Self::Other(format)
+        }
+    }
+
+    pub fn rtv_format(&self) -> u32 {
+        match self {
+            Self::Rgba8Unorm => 28,
+            Self::Rgba8UnormSrgb => 29,
+            Self::Bgra8Unorm => 87,
+            Self::Bgra8UnormSrgb => 91,
+            Self::Bgrx8Unorm => 88,
+            Self::Bgrx8UnormSrgb => 93,
+            Self::Rgb10A2Unorm => 24,
+            Self::Rgba16Float => 10,
+            Self::Other(format) => *format
+        }
+    }
+
+    pub fn encoding(&self) -> ColorEncoding {
+        self.encoding_for(None)
+    }
+
+    pub fn encoding_for(&self, color_space: Option<u32>) -> ColorEncoding {
+        match self {
+            Self::Rgba8UnormSrgb | Self::Bgra8UnormSrgb | Self::Bgrx8UnormSrgb => ColorEncoding::Linear,
+            Self::Rgb10A2Unorm if color_space == Some(ColorOutput::COLOR_SPACE_G2084_P2020) => ColorEncoding::Hdr10,
+            Self::Rgba16Float => ColorEncoding::Scrgb,
+            _ => ColorEncoding::Srgb
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorEncoding {
+    Srgb,
+    Linear,
+    Scrgb,
+    Hdr10
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ColorOutputConstants {
+    pub scale: f32,
+    pub padding: [f32; 3]
+}
+constant_layout!(ColorOutputConstants { scale, padding });
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ColorOutput {
+    pub format: BackbufferFormat,
+    pub encoding: ColorEncoding,
+    pub paper_white_nits: f32
+}
+impl ColorOutput {
+    pub const DEFAULT_PAPER_WHITE_NITS: f32 = 200.0;
+    pub const SCRGB_REFERENCE_NITS: f32 = 80.0;
+    pub const PQ_PEAK_NITS: f32 = 10000.0;
+    pub const COLOR_SPACE_G22_P709: u32 = 0;
+    pub const COLOR_SPACE_G10_P709: u32 = 1;
+    pub const COLOR_SPACE_G2084_P2020: u32 = 12;
+
+    const REC709_TO_REC2020: [[f32; 3]; 3] = [
+        [0.627404, 0.329282, 0.0433136],
+        [0.069097, 0.91954, 0.0113612],
+        [0.0163916, 0.0880132, 0.895595]
+    ];
+
+    pub fn detect(format: u32) -> Self {
+        Self::detect_with_color_space(format, None)
+    }
+
+    pub fn detect_with_color_space(format: u32, color_space: Option<u32>) -> Self {
+        let format = BackbufferFormat::from_dxgi(format);
+
+        Self { format, encoding: format.encoding_for(color_space), paper_white_nits: Self::DEFAULT_PAPER_WHITE_NITS }
+    }
+
+    pub fn encoding(mut self, encoding: ColorEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn paper_white(mut self, nits: f32) -> Self {
+        self.paper_white_nits = nits;
+        self
+    }
+
+    pub fn rtv_format(&self) -> u32 {
+        self.format.rtv_format()
+    }
+
+    pub fn constants(&self) -> ColorOutputConstants {
+        let scale = match self.encoding {
+            ColorEncoding::Srgb | ColorEncoding::Linear => 1.0,
+            ColorEncoding::Scrgb => self.paper_white_nits / Self::SCRGB_REFERENCE_NITS,
+            ColorEncoding::Hdr10 => self.paper_white_nits / Self::PQ_PEAK_NITS
+        };
+
+        ColorOutputConstants { scale, padding: [0.0; 3] }
+    }
+
+    pub fn encode(&self, color: Color) -> [f32; 4] {
+        let scale = self.constants().scale;
+        let linear = color.to_linear();
+
+        match self.encoding {
+            ColorEncoding::Srgb => color.to_array(),
+            ColorEncoding::Linear => linear.to_array(),
+            ColorEncoding::Scrgb => [linear.r * scale, linear.g * scale, linear.b * scale, color.a],
+            ColorEncoding::Hdr10 => {
+                let rgb = [linear.r, linear.g, linear.b];
+                let [r, g, b] = Self::REC709_TO_REC2020.map(|row| {
+                    pq_encode((row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]) * scale)
+                });
+
+                [r, g, b, color.a]
+            }
+        }
+    }
+}
+
+pub fn pq_encode(value: f32) -> f32 {
+    const M1: f32 = 2610.0 / 16384.0;
+    const M2: f32 = 2523.0 / 4096.0 * 128.0;
+    const C1: f32 = 3424.0 / 4096.0;
+    const C2: f32 = 2413.0 / 4096.0 * 32.0;
+    const C3: f32 = 2392.0 / 4096.0 * 32.0;
+
+    let y = value.max(0.0).powf(M1);
+    ((C1 + C2 * y) / (1.0 + C3 * y)).powf(M2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn pq_encode_matches_reference_points() {
+        assert!(pq_encode(0.0) < 1e-6);
+        assert!(pq_encode(-1.0) < 1e-6);
+        assert_close(pq_encode(1.0), 1.0);
+        assert_close(pq_encode(100.0 / ColorOutput::PQ_PEAK_NITS), 0.5081);
+        assert_close(pq_encode(1000.0 / ColorOutput::PQ_PEAK_NITS), 0.7518);
+    }
+
+    #[test]
+    fn srgb_output_passes_colors_through() {
+        let output = ColorOutput::detect(28);
+        assert_eq!(output.encoding, ColorEncoding::Srgb);
+        assert_eq!(output.encode(Color::rgba(0.5, 0.25, 1.0, 0.5)), [0.5, 0.25, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn srgb_backbuffer_receives_linear_values() {
+        let output = ColorOutput::detect(29);
+        assert_eq!(output.encoding, ColorEncoding::Linear);
+
+        let [r, g, b, a] = output.encode(Color::rgba(0.5, 0.04045, 1.0, 0.75));
+        assert_close(r, 0.2140);
+        assert_close(g, 0.04045 / 12.92);
+        assert_close(b, 1.0);
+        assert_close(a, 0.75);
+    }
+
+    #[test]
+    fn scrgb_scales_by_paper_white() {
+        let output = ColorOutput::detect(10).paper_white(200.0);
+        assert_eq!(output.encoding, ColorEncoding::Scrgb);
+        assert_close(output.constants().scale, 2.5);
+
+        let [r, g, b, a] = output.encode(Color::rgba(1.0, 0.5, 0.0, 0.5));
+        assert_close(r, 2.5);
+        assert_close(g, crate::srgb_to_linear(0.5) * 2.5);
+        assert_close(b, 0.0);
+        assert_close(a, 0.5);
+    }
+
+    #[test]
+    fn hdr10_encodes_white_through_pq() {
+        let output = ColorOutput::detect_with_color_space(24, Some(ColorOutput::COLOR_SPACE_G2084_P2020)).paper_white(ColorOutput::PQ_PEAK_NITS);
+        assert_eq!(output.encoding, ColorEncoding::Hdr10);
+
+        let [r, g, b, _] = output.encode(Color::WHITE);
+        assert_close(r, 1.0);
+        assert_close(g, 1.0);
+        assert_close(b, 1.0);
+
+        let [r, _, _, _] = output.paper_white(100.0).encode(Color::WHITE);
+        assert_close(r, 0.5081);
+    }
+
+    #[test]
+    fn ten_bit_backbuffers_default_to_srgb() {
+        assert_eq!(ColorOutput::detect(24).encoding, ColorEncoding::Srgb);
+        assert_eq!(ColorOutput::detect_with_color_space(24, Some(ColorOutput::COLOR_SPACE_G22_P709)).encoding, ColorEncoding::Srgb);
+        assert_eq!(ColorOutput::detect_with_color_space(24, Some(ColorOutput::COLOR_SPACE_G2084_P2020)).encoding, ColorEncoding::Hdr10);
+        assert_eq!(ColorOutput::detect(24).encoding(ColorEncoding::Hdr10).encoding, ColorEncoding::Hdr10);
+    }
+}
\ No newline at end of file
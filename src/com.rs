@@ -0,0 +1,238 @@
+use crate::dependencies::{
+    c_void, null_mut, forget, NonNull, Deref, PhantomData
+};
+
+#[cfg(windows)]
+use crate::dependencies::{
+    Interface
+};
+
+#[repr(C)]
+pub struct UnknownVtbl {
+    pub query_interface: unsafe extern "system" fn(*mut c_void, *const c_void, *mut *mut c_void) -> i32,
+    pub add_ref: unsafe extern "system" fn(*mut c_void) -> u32,
+    pub release: unsafe extern "system" fn(*mut c_void) -> u32
+}
+
+pub struct ComPtr<T> {
+    ptr: NonNull<T>,
+    marker: PhantomData<T>
+}
+impl<T> ComPtr<T> {
+    /// # Safety
+    /// `ptr` must be null or a live COM object whose reference is handed over to the returned pointer.
+    pub unsafe fn from_raw(ptr: *mut T) -> Option<Self> {
+        NonNull::new(ptr).map(|ptr| Self { ptr, marker: PhantomData })
+    }
+
+    /// # Safety
+    /// `ptr` must be null or a live COM object; a new reference is taken with `AddRef`.
+    pub unsafe fn from_raw_borrowed(ptr: *mut T) -> Option<Self> {
+        let com_ptr = unsafe { Self::from_raw(ptr) }?;
+        com_ptr.add_ref();
+
+        Some(com_ptr)
+    }
+
+    pub fn as_ptr(&self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    pub fn as_ptr_or_null(com_ptr: &Option<Self>) -> *mut T {
+        match com_ptr {
+            Some(com_ptr) => com_ptr.as_ptr(),
+            None => null_mut()
+        }
+    }
+
+    pub fn into_raw(self) -> *mut T {
+        let ptr = self.as_ptr();
+        forget(self);
+
+        ptr
+    }
+
+    #[cfg(windows)]
+    pub fn cast<U: Interface>(&self) -> Option<ComPtr<U>> {
+        self.query(&U::uuidof() as *const _ as *const c_void)
+    }
+
+    #[cfg(any(windows, test))]
+    fn query<U>(&self, iid: *const c_void) -> Option<ComPtr<U>> {
+        let mut ptr = null_mut::<U>();
+        let hr = unsafe { ((*self.vtbl()).query_interface)(
+            self.as_ptr() as *mut c_void,
+            iid,
+            &mut ptr as *mut _ as *mut *mut c_void
+        ) };
+
+        if hr >= 0 { return unsafe { ComPtr::from_raw(ptr) } };
+
+        None
+    }
+
+    fn vtbl(&self) -> *const UnknownVtbl {
+        unsafe { *(self.as_ptr() as *const *const UnknownVtbl) }
+    }
+
+    fn add_ref(&self) -> u32 {
+        unsafe { ((*self.vtbl()).add_ref)(self.as_ptr() as *mut c_void) }
+    }
+
+    fn release(&self) -> u32 {
+        unsafe { ((*self.vtbl()).release)(self.as_ptr() as *mut c_void) }
+    }
+}
+impl<T> Clone for ComPtr<T> {
+    fn clone(&self) -> Self {
+        self.add_ref();
+
+        Self { ptr: self.ptr, marker: PhantomData }
+    }
+}
+impl<T> Drop for ComPtr<T> {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+impl<T> Deref for ComPtr<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+impl<T> PartialEq for ComPtr<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const E_NOINTERFACE: i32 = 0x80004002u32 as i32;
+
+    static IID_FAKE: u8 = 0;
+    static IID_OTHER: u8 = 0;
+    static VTBL: UnknownVtbl = UnknownVtbl { query_interface, add_ref, release };
+
+    #[repr(C)]
+    struct FakeObject {
+        vtbl: *const UnknownVtbl,
+        add_refs: Cell<u32>,
+        releases: Cell<u32>
+    }
+    impl FakeObject {
+        fn new() -> Box<Self> {
+            Box::new(Self { vtbl: &VTBL, add_refs: Cell::new(0), releases: Cell::new(0) })
+        }
+
+        fn counts(&self) -> (u32, u32) {
+            (self.add_refs.get(), self.releases.get())
+        }
+    }
+
+    unsafe extern "system" fn query_interface(this: *mut c_void, iid: *const c_void, out: *mut *mut c_void) -> i32 {
+        if iid != &IID_FAKE as *const u8 as *const c_void {
+            unsafe { *out = null_mut() };
+            return E_NOINTERFACE;
+        }
+
+        unsafe {
+            add_ref(this);
+            *out = this;
+        }
+
+        0
+    }
+
+    unsafe extern "system" fn add_ref(this: *mut c_void) -> u32 {
+        let object = unsafe { &*(this as *const FakeObject) };
+        object.add_refs.set(object.add_refs.get() + 1);
+
+        object.add_refs.get()
+    }
+
+    unsafe extern "system" fn release(this: *mut c_void) -> u32 {
+        let object = unsafe { &*(this as *const FakeObject) };
+        object.releases.set(object.releases.get() + 1);
+
+        object.releases.get()
+    }
+
+    fn com_ptr(object: &FakeObject) -> ComPtr<FakeObject> {
+        unsafe { ComPtr::from_raw(object as *const _ as *mut FakeObject) }.unwrap()
+    }
+
+    #[test]
+    fn from_raw_takes_ownership_without_add_ref() {
+        let object = FakeObject::new();
+        let ptr = com_ptr(&object);
+        assert_eq!(object.counts(), (0, 0));
+
+        drop(ptr);
+        assert_eq!(object.counts(), (0, 1));
+    }
+
+    #[test]
+    fn null_pointers_are_rejected() {
+        assert!(unsafe { ComPtr::<FakeObject>::from_raw(null_mut()) }.is_none());
+        assert!(unsafe { ComPtr::<FakeObject>::from_raw_borrowed(null_mut()) }.is_none());
+    }
+
+    #[test]
+    fn clone_adds_a_reference_and_drop_releases_it() {
+        let object = FakeObject::new();
+        let ptr = com_ptr(&object);
+        let copy = ptr.clone();
+        assert_eq!(object.counts(), (1, 0));
+        assert!(ptr == copy);
+
+        drop(copy);
+        assert_eq!(object.counts(), (1, 1));
+        drop(ptr);
+        assert_eq!(object.counts(), (1, 2));
+    }
+
+    #[test]
+    fn into_raw_keeps_the_reference() {
+        let object = FakeObject::new();
+        let raw = com_ptr(&object).into_raw();
+        assert_eq!(raw as *const FakeObject, &*object as *const FakeObject);
+        assert_eq!(object.counts(), (0, 0));
+
+        drop(unsafe { ComPtr::from_raw(raw) });
+        assert_eq!(object.counts(), (0, 1));
+    }
+
+    #[test]
+    fn from_raw_borrowed_adds_a_reference() {
+        let object = FakeObject::new();
+        let ptr = unsafe { ComPtr::from_raw_borrowed(&*object as *const _ as *mut FakeObject) }.unwrap();
+        assert_eq!(object.counts(), (1, 0));
+
+        drop(ptr);
+        assert_eq!(object.counts(), (1, 1));
+    }
+
+    #[test]
+    fn query_adds_a_reference_only_on_success() {
+        let object = FakeObject::new();
+        let ptr = com_ptr(&object);
+
+        let other = ptr.query::<FakeObject>(&IID_OTHER as *const u8 as *const c_void);
+        assert!(other.is_none());
+        assert_eq!(object.counts(), (0, 0));
+
+        let cast = ptr.query::<FakeObject>(&IID_FAKE as *const u8 as *const c_void).unwrap();
+        assert!(cast.as_ptr() == ptr.as_ptr());
+        assert_eq!(object.counts(), (1, 0));
+
+        drop(cast);
+        drop(ptr);
+        assert_eq!(object.counts(), (1, 2));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,209 @@
+use crate::dependencies::{
+    size_of
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ConstantField {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+    pub elements: usize
+}
+impl ConstantField {
+    pub fn size_of<T, F>(_field: fn(&T) -> &F) -> usize {
+        size_of::<F>()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PackingError {
+    SizeNotMultipleOf16 { size: usize },
+    ComponentMisaligned { field: &'static str, offset: usize, size: usize },
+    StraddlesRegister { field: &'static str, offset: usize, size: usize },
+    AggregateMisaligned { field: &'static str, offset: usize },
+    Overlap { field: &'static str, offset: usize },
+    Gap { offset: usize, size: usize },
+    OutOfBounds { field: &'static str, offset: usize, size: usize },
+    ArrayStride { field: &'static str, elements: usize, size: usize }
+}
+
+/// # Safety
+/// `fields` must describe every field of `Self` with its real offset and size, and `Self` must be `#[repr(C)]`.
+/// Padding has to be spelled out as explicit fields, since the whole of `Self` is uploaded as bytes.
+/// HLSL arrays are described with `elements > 0`, and each element has to be padded to a 16-byte register.
+pub unsafe trait ConstantLayout: Copy {
+    fn fields() -> Vec<ConstantField>;
+
+    fn validate() -> Result<(), PackingError> {
+        validate_packing(size_of::<Self>(), &Self::fields())
+    }
+}
+
+pub fn validate_packing(size: usize, fields: &[ConstantField]) -> Result<(), PackingError> {
+    const REGISTER: usize = 16;
+    const COMPONENT: usize = 4;
+
+    if size == 0 || !size.is_multiple_of(REGISTER) { return Err(PackingError::SizeNotMultipleOf16 { size }) };
+
+    let total = size;
+    let mut fields = fields.to_vec();
+    fields.sort_by_key(|field| field.offset);
+
+    let mut end = 0;
+    for field in fields.iter() {
+        let ConstantField { name, offset, size, elements } = *field;
+
+        if offset < end { return Err(PackingError::Overlap { field: name, offset }) };
+        if offset + size > total { return Err(PackingError::OutOfBounds { field: name, offset, size }) };
+        if offset > end { return Err(PackingError::Gap { offset: end, size: offset - end }) };
+        if !offset.is_multiple_of(COMPONENT) || !size.is_multiple_of(COMPONENT) || size == 0 {
+            return Err(PackingError::ComponentMisaligned { field: name, offset, size });
+        }
+        if elements > 0 && (!size.is_multiple_of(elements) || !(size / elements).is_multiple_of(REGISTER)) {
+            return Err(PackingError::ArrayStride { field: name, elements, size });
+        }
+        if (size > REGISTER || elements > 0) && !offset.is_multiple_of(REGISTER) {
+            return Err(PackingError::AggregateMisaligned { field: name, offset });
+        }
+        if size <= REGISTER && offset / REGISTER != (offset + size - 1) / REGISTER {
+            return Err(PackingError::StraddlesRegister { field: name, offset, size });
+        }
+
+        end = offset + size;
+    }
+
+    if end < total { return Err(PackingError::Gap { offset: end, size: total - end }) };
+
+    Ok(())
+}
+
+#[macro_export]
+macro_rules! constant_layout {
+    (@elements) => { 0 };
+    (@elements $elements:literal) => { $elements };
+    ($ty:ty { $($field:ident $([$elements:literal])?),* $(,)? }) => {
+        unsafe impl $crate::ConstantLayout for $ty {
+            fn fields() -> Vec<$crate::ConstantField> {
+                vec![$($crate::ConstantField {
+                    name: stringify!($field),
+                    offset: ::core::mem::offset_of!($ty, $field),
+                    size: $crate::ConstantField::size_of(|value: &$ty| &value.$field),
+                    elements: $crate::constant_layout!(@elements $($elements)?)
+                }),*]
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &'static str, offset: usize, size: usize) -> ConstantField {
+        ConstantField { name, offset, size, elements: 0 }
+    }
+
+    fn array(name: &'static str, offset: usize, size: usize, elements: usize) -> ConstantField {
+        ConstantField { name, offset, size, elements }
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Transform {
+        matrix: [[f32; 4]; 4],
+        tint: [f32; 3],
+        time: f32
+    }
+    crate::constant_layout!(Transform { matrix, tint, time });
+
+    #[repr(C, align(16))]
+    #[derive(Clone, Copy)]
+    struct Padded {
+        color: [f32; 4],
+        time: f32
+    }
+    crate::constant_layout!(Padded { color, time });
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Weights {
+        weights: [[f32; 4]; 3],
+        count: u32,
+        padding: [u32; 3]
+    }
+    crate::constant_layout!(Weights { weights[3], count, padding });
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct PackedWeights {
+        weights: [f32; 4]
+    }
+    crate::constant_layout!(PackedWeights { weights[4] });
+
+    #[test]
+    fn accepts_packed_layouts() {
+        assert_eq!(Transform::validate(), Ok(()));
+        assert_eq!(validate_packing(16, &[field("a", 0, 8), field("b", 8, 4), field("c", 12, 4)]), Ok(()));
+        assert_eq!(validate_packing(32, &[field("b", 16, 16), field("a", 0, 16)]), Ok(()));
+    }
+
+    #[test]
+    fn rejects_straddling_fields() {
+        assert_eq!(
+            validate_packing(32, &[field("a", 0, 8), field("b", 8, 12), field("c", 20, 12)]),
+            Err(PackingError::StraddlesRegister { field: "b", offset: 8, size: 12 })
+        );
+        assert_eq!(
+            validate_packing(48, &[field("a", 0, 4), field("b", 4, 32), field("c", 36, 12)]),
+            Err(PackingError::AggregateMisaligned { field: "b", offset: 4 })
+        );
+    }
+
+    #[test]
+    fn rejects_overlap() {
+        assert_eq!(
+            validate_packing(16, &[field("a", 0, 8), field("b", 4, 12)]),
+            Err(PackingError::Overlap { field: "b", offset: 4 })
+        );
+    }
+
+    #[test]
+    fn rejects_bad_size() {
+        assert_eq!(validate_packing(0, &[]), Err(PackingError::SizeNotMultipleOf16 { size: 0 }));
+        assert_eq!(validate_packing(20, &[field("a", 0, 20)]), Err(PackingError::SizeNotMultipleOf16 { size: 20 }));
+        assert_eq!(
+            validate_packing(16, &[field("a", 0, 2), field("b", 2, 14)]),
+            Err(PackingError::ComponentMisaligned { field: "a", offset: 0, size: 2 })
+        );
+        assert_eq!(
+            validate_packing(16, &[field("a", 0, 12), field("b", 12, 8)]),
+            Err(PackingError::OutOfBounds { field: "b", offset: 12, size: 8 })
+        );
+    }
+
+    #[test]
+    fn rejects_gaps_and_trailing_padding() {
+        assert_eq!(
+            validate_packing(32, &[field("a", 0, 4), field("b", 16, 16)]),
+            Err(PackingError::Gap { offset: 4, size: 12 })
+        );
+        assert_eq!(Padded::validate(), Err(PackingError::Gap { offset: 20, size: 12 }));
+    }
+
+    #[test]
+    fn validates_array_strides() {
+        assert_eq!(Weights::fields()[0].elements, 3);
+        assert_eq!(Weights::fields()[1].elements, 0);
+        assert_eq!(Weights::validate(), Ok(()));
+        assert_eq!(PackedWeights::validate(), Err(PackingError::ArrayStride { field: "weights", elements: 4, size: 16 }));
+        assert_eq!(
+            validate_packing(48, &[array("a", 0, 40, 2), field("b", 40, 8)]),
+            Err(PackingError::ArrayStride { field: "a", elements: 2, size: 40 })
+        );
+        assert_eq!(
+            validate_packing(48, &[field("a", 0, 4), array("b", 4, 32, 2), field("c", 36, 12)]),
+            Err(PackingError::AggregateMisaligned { field: "b", offset: 4 })
+        );
+        assert_eq!(validate_packing(32, &[array("a", 0, 32, 2)]), Ok(()));
+    }
+}
\ No newline at end of file
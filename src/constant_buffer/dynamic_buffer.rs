@@ -0,0 +1,67 @@
+use crate::dependencies::{
+    null_mut, zeroed, copy_nonoverlapping, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11Buffer, ID3D11Resource, D3D11_BUFFER_DESC, D3D11_SUBRESOURCE_DATA, D3D11_MAPPED_SUBRESOURCE, D3D11_USAGE_DYNAMIC, D3D11_BIND_CONSTANT_BUFFER, D3D11_CPU_ACCESS_WRITE, D3D11_MAP_WRITE_DISCARD
+};
+
+use crate::{ ComPtr };
+
+#[derive(Clone)]
+pub struct DynamicBuffer {
+    pub buffer: ComPtr<ID3D11Buffer>,
+    pub size: usize
+}
+impl DynamicBuffer {
+    pub fn create(device: &ComPtr<ID3D11Device>, data: &[u8]) -> Option<Self> {
+        let desc = D3D11_BUFFER_DESC {
+            ByteWidth: data.len() as u32,
+            Usage: D3D11_USAGE_DYNAMIC,
+            BindFlags: D3D11_BIND_CONSTANT_BUFFER,
+            CPUAccessFlags: D3D11_CPU_ACCESS_WRITE,
+            MiscFlags: 0,
+            StructureByteStride: 0
+        };
+
+        let initial_data = D3D11_SUBRESOURCE_DATA {
+            pSysMem: data.as_ptr() as *const _,
+            SysMemPitch: 0,
+            SysMemSlicePitch: 0
+        };
+
+        let mut buffer = null_mut::<ID3D11Buffer>();
+        let hr = unsafe { device.CreateBuffer(&desc, &initial_data, &mut buffer) };
+
+        if !SUCCEEDED(hr) { return None };
+
+        let buffer = unsafe { ComPtr::from_raw(buffer) }?;
+
+        Some(Self { buffer, size: data.len() })
+    }
+
+    pub fn update(&self, context: &ComPtr<ID3D11DeviceContext>, data: &[u8]) -> bool {
+        if data.len() != self.size { return false };
+
+        let resource = self.buffer.as_ptr() as *mut ID3D11Resource;
+        let mut mapped = unsafe { zeroed::<D3D11_MAPPED_SUBRESOURCE>() };
+        let hr = unsafe { context.Map(resource, 0, D3D11_MAP_WRITE_DISCARD, 0, &mut mapped) };
+
+        if !SUCCEEDED(hr) { return false };
+
+        unsafe {
+            copy_nonoverlapping(data.as_ptr(), mapped.pData as *mut u8, data.len());
+            context.Unmap(resource, 0);
+        }
+
+        true
+    }
+
+    pub fn bind_vertex(&self, context: &ComPtr<ID3D11DeviceContext>, slot: u32) {
+        let buffer = self.buffer.as_ptr();
+        unsafe { context.VSSetConstantBuffers(slot, 1, &buffer) };
+    }
+
+    pub fn bind_pixel(&self, context: &ComPtr<ID3D11DeviceContext>, slot: u32) {
+        let buffer = self.buffer.as_ptr();
+        unsafe { context.PSSetConstantBuffers(slot, 1, &buffer) };
+    }
+}
+unsafe impl Send for DynamicBuffer {}
+unsafe impl Sync for DynamicBuffer {}
\ No newline at end of file
@@ -0,0 +1,54 @@
+mod constant_layout;
+pub use constant_layout::{ ConstantLayout, ConstantField, PackingError, validate_packing };
+
+#[cfg(windows)]
+mod dynamic_buffer;
+#[cfg(windows)]
+pub use dynamic_buffer::DynamicBuffer;
+
+#[cfg(windows)]
+use crate::dependencies::{
+    size_of, from_raw_parts, PhantomData, ID3D11Device, ID3D11DeviceContext
+};
+
+#[cfg(windows)]
+use crate::{ ComPtr };
+
+#[cfg(windows)]
+#[derive(Clone)]
+pub struct ConstantBuffer<T: ConstantLayout> {
+    pub buffer: DynamicBuffer,
+    marker: PhantomData<T>
+}
+#[cfg(windows)]
+impl<T: ConstantLayout> ConstantBuffer<T> {
+    pub fn create(device: &ComPtr<ID3D11Device>, value: &T) -> Option<Self> {
+        let packing = T::validate();
+        debug_assert_eq!(packing, Ok(()), "constant buffer layout does not follow HLSL packing");
+        if packing.is_err() { return None };
+
+        let buffer = DynamicBuffer::create(device, Self::bytes(value))?;
+
+        Some(Self { buffer, marker: PhantomData })
+    }
+
+    pub fn update(&self, context: &ComPtr<ID3D11DeviceContext>, value: &T) -> bool {
+        self.buffer.update(context, Self::bytes(value))
+    }
+
+    pub fn bind_vertex(&self, context: &ComPtr<ID3D11DeviceContext>, slot: u32) {
+        self.buffer.bind_vertex(context, slot);
+    }
+
+    pub fn bind_pixel(&self, context: &ComPtr<ID3D11DeviceContext>, slot: u32) {
+        self.buffer.bind_pixel(context, slot);
+    }
+
+    fn bytes(value: &T) -> &[u8] {
+        unsafe { from_raw_parts(value as *const T as *const u8, size_of::<T>()) }
+    }
+}
+#[cfg(windows)]
+unsafe impl<T: ConstantLayout> Send for ConstantBuffer<T> {}
+#[cfg(windows)]
+unsafe impl<T: ConstantLayout> Sync for ConstantBuffer<T> {}
\ No newline at end of file
@@ -1,33 +1,54 @@
+#![allow(unused_imports)]
+
 pub use std::{
-    os::{
-        windows::{
-            ffi::{ OsStrExt }
-        }
-    },
     iter::{ once },
-    ffi::{ c_int, OsStr, CString },
-    ptr::{ null, null_mut, copy_nonoverlapping },
-    mem::{ zeroed, size_of }
+    ffi::{ c_int, c_void, OsStr, CStr, CString },
+    fs::{ read, write, metadata, rename, remove_file, read_dir, create_dir_all, File },
+    path::{ Path, PathBuf },
+    sync::{ Arc, Mutex, atomic::{ AtomicBool, Ordering }, mpsc::{ channel, Sender, Receiver } },
+    thread::{ spawn, sleep },
+    time::{ Duration, SystemTime },
+    collections::{ HashMap, HashSet },
+    ptr::{ null, null_mut, copy_nonoverlapping, NonNull },
+    slice::{ from_raw_parts },
+    mem::{ zeroed, size_of, forget, take },
+    marker::{ PhantomData },
+    ops::{ Deref }
 };
 
+#[cfg(windows)]
+pub use std::os::windows::ffi::{ OsStrExt };
+
+#[cfg(windows)]
 pub use winapi::{
     um::{
-        winuser::{ WNDPROC, WNDCLASSEXW, CS_HREDRAW, CS_VREDRAW, WS_OVERLAPPEDWINDOW, GetClientRect, RegisterClassExW, CreateWindowExW, DefWindowProcW, DestroyWindow, UnregisterClassW },
+        winuser::{ WNDPROC, WNDCLASSEXW, CS_HREDRAW, CS_VREDRAW, WS_OVERLAPPEDWINDOW, GetClientRect, RegisterClassExW, CreateWindowExW, DefWindowProcW, DestroyWindow, UnregisterClassW, AdjustWindowRectEx, LoadCursorW, IDC_ARROW, CW_USEDEFAULT, CREATESTRUCTW, GWLP_USERDATA, GetWindowLongPtrW, SetWindowLongPtrW, SetWindowPos, SWP_NOZORDER, SWP_NOACTIVATE, MSG, GetMessageW, PeekMessageW, PM_REMOVE, TranslateMessage, DispatchMessageW, WM_NCCREATE, WM_NCDESTROY, WM_CLOSE, WM_DPICHANGED, WM_QUIT, GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT, WS_EX_TOPMOST, WS_EX_TOOLWINDOW, WS_POPUP, WS_VISIBLE, SetLayeredWindowAttributes, LWA_ALPHA, EnumWindows, GetWindowTextW, GetClassNameW, GetWindowThreadProcessId, IsWindow, IsWindowVisible, IsIconic, ClientToScreen, GetForegroundWindow, GetWindowRect, ShowWindow, SW_HIDE, SW_SHOWNOACTIVATE, HWND_TOPMOST, EnumDisplayDevicesW },
+        dwmapi::{ DwmExtendFrameIntoClientArea },
+        wingdi::{ DISPLAY_DEVICEW },
+        uxtheme::{ MARGINS },
         libloaderapi::{ GetModuleHandleW },
         memoryapi::{ VirtualAlloc },
         winnt::{ MEM_COMMIT, PAGE_READWRITE },
-        d3dcommon::{ D3D_DRIVER_TYPE_HARDWARE, D3D_FEATURE_LEVEL_10_1, D3D_FEATURE_LEVEL_11_0, ID3DBlob },
-        d3d11::{ D3D11_SDK_VERSION, D3D11CreateDeviceAndSwapChain, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, ID3D11RenderTargetView, ID3D11DepthStencilView, ID3D11Resource, ID3D11Buffer, ID3D11VertexShader, ID3D11PixelShader, ID3D11InputLayout, D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_VERTEX_DATA, D3D11_BUFFER_DESC, D3D11_BIND_VERTEX_BUFFER, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT, D3D11_BIND_INDEX_BUFFER },
-        d3dcompiler::{ D3DCompile, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION }
+        d3dcommon::{ D3D_SHADER_MACRO, D3D_INCLUDE_TYPE, D3D_INCLUDE_LOCAL, ID3DInclude, ID3DIncludeVtbl, D3D_DRIVER_TYPE_HARDWARE, D3D_DRIVER_TYPE_UNKNOWN, D3D_FEATURE_LEVEL, D3D_FEATURE_LEVEL_10_1, D3D_FEATURE_LEVEL_11_0, ID3DBlob },
+        d3d11::{ D3D11_SDK_VERSION, D3D11CreateDeviceAndSwapChain, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, ID3D11RenderTargetView, ID3D11DepthStencilView, ID3D11Resource, ID3D11Buffer, ID3D11VertexShader, ID3D11PixelShader, ID3D11InputLayout, ID3D11ShaderResourceView, D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_VERTEX_DATA, D3D11_BUFFER_DESC, D3D11_BIND_VERTEX_BUFFER, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT, D3D11_BIND_INDEX_BUFFER, D3D11_MAPPED_SUBRESOURCE, D3D11_USAGE_DYNAMIC, D3D11_BIND_CONSTANT_BUFFER, D3D11_CPU_ACCESS_WRITE, D3D11_MAP_WRITE_DISCARD, D3D11_VIEWPORT, D3D11_RENDER_TARGET_VIEW_DESC, D3D11_TEXTURE2D_DESC, D3D11_RTV_DIMENSION_TEXTURE2D, D3D11_RTV_DIMENSION_TEXTURE2DMS, ID3D11BlendState, D3D11_BLEND_DESC, D3D11_RENDER_TARGET_BLEND_DESC, D3D11_BLEND_ONE, D3D11_BLEND_SRC_ALPHA, D3D11_BLEND_INV_SRC_ALPHA, D3D11_BLEND_OP_ADD, D3D11_COLOR_WRITE_ENABLE_ALL, D3D11_BIND_SHADER_RESOURCE, D3D11_BIND_RENDER_TARGET },
     },
     shared::{
-        dxgi::{ DXGI_SWAP_CHAIN_DESC, DXGI_SWAP_EFFECT_DISCARD, DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH, IDXGIFactory, IDXGIAdapter, IDXGISwapChain, IDXGIDevice, IDXGISurface },
+        dxgi::{ IDXGIFactory1, IDXGIAdapter1, IDXGIOutput, DXGI_ADAPTER_DESC1, DXGI_OUTPUT_DESC, DXGI_ADAPTER_FLAG_SOFTWARE, CreateDXGIFactory1, DXGI_SWAP_CHAIN_DESC, DXGI_SWAP_EFFECT_DISCARD, DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH, IDXGIFactory, IDXGIAdapter, IDXGISwapChain, IDXGIDevice, IDXGISurface },
+        dxgi1_4::{ IDXGISwapChain3, DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT },
+        dxgi1_5::{ IDXGIFactory5, DXGI_FEATURE_PRESENT_ALLOW_TEARING },
         dxgitype::{ DXGI_RATIONAL, DXGI_MODE_DESC, DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED, DXGI_MODE_SCALING_UNSPECIFIED, DXGI_SAMPLE_DESC, DXGI_USAGE_RENDER_TARGET_OUTPUT },
-        dxgiformat::{ DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT, DXGI_FORMAT_R16_UINT, DXGI_FORMAT_R32_UINT },
-        windef::{ RECT, HWND, HICON, HCURSOR, HBRUSH, HMENU },
-        minwindef::{ UINT, LPVOID, DWORD, HINSTANCE },
-        winerror::{ SUCCEEDED },
-        ntdef::{ NULL, LPCWSTR }
+        dxgiformat::{ DXGI_FORMAT, DXGI_FORMAT_UNKNOWN, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT, DXGI_FORMAT_R32_UINT, DXGI_FORMAT_R32G32_UINT, DXGI_FORMAT_R32G32B32_UINT, DXGI_FORMAT_R32G32B32A32_UINT, DXGI_FORMAT_R32_SINT, DXGI_FORMAT_R32G32_SINT, DXGI_FORMAT_R32G32B32_SINT, DXGI_FORMAT_R32G32B32A32_SINT, DXGI_FORMAT_R16_UINT },
+        windef::{ RECT, POINT, HWND, HICON, HCURSOR, HBRUSH, HMENU },
+        minwindef::{ UINT, LPVOID, LPCVOID, DWORD, HINSTANCE, BOOL, TRUE, FALSE, WPARAM, LPARAM, LRESULT },
+        basetsd::{ LONG_PTR },
+        winerror::{ SUCCEEDED, HRESULT, S_OK, E_FAIL },
+        ntdef::{ NULL, LPCWSTR, LPCSTR }
     },
     Interface
-};
\ No newline at end of file
+};
+
+#[cfg(all(windows, feature = "compiler"))]
+pub use winapi::um::d3dcompiler::{ D3DCompile, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION, D3DCOMPILE_OPTIMIZATION_LEVEL0, D3DCOMPILE_OPTIMIZATION_LEVEL1, D3DCOMPILE_OPTIMIZATION_LEVEL2, D3DCOMPILE_OPTIMIZATION_LEVEL3 };
+
+#[cfg(all(windows, feature = "diagnostics"))]
+pub use winapi::um::d3d11sdklayers::{ ID3D11InfoQueue, D3D11_MESSAGE };
\ No newline at end of file
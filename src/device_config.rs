@@ -0,0 +1,229 @@
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeatureLevel {
+    Level9_1,
+    Level9_2,
+    Level9_3,
+    Level10_0,
+    Level10_1,
+    Level11_0,
+    Level11_1,
+    Level12_0,
+    Level12_1
+}
+impl FeatureLevel {
+    pub const ALL: [FeatureLevel; 9] = [
+        Self::Level9_1,
+        Self::Level9_2,
+        Self::Level9_3,
+        Self::Level10_0,
+        Self::Level10_1,
+        Self::Level11_0,
+        Self::Level11_1,
+        Self::Level12_0,
+        Self::Level12_1
+    ];
+
+    pub fn d3d(&self) -> u32 {
+        match self {
+            Self::Level9_1 => 0x9100,
+            Self::Level9_2 => 0x9200,
+            Self::Level9_3 => 0x9300,
+            Self::Level10_0 => 0xa000,
+            Self::Level10_1 => 0xa100,
+            Self::Level11_0 => 0xb000,
+            Self::Level11_1 => 0xb100,
+            Self::Level12_0 => 0xc000,
+            Self::Level12_1 => 0xc100
+        }
+    }
+
+    pub fn from_d3d(level: u32) -> Self {
+        Self::ALL.iter()
+            .rev()
+            .find(|known| known.d3d() <= level)
+            .copied()
+            .unwrap_or(Self::Level9_1)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DriverType {
+    Hardware,
+    Warp,
+    Reference
+}
+impl DriverType {
+    pub fn d3d(&self) -> u32 {
+        match self {
+            Self::Hardware => 1,
+            Self::Reference => 2,
+            Self::Warp => 5
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeviceAttempt {
+    pub driver_type: DriverType,
+    pub flags: u32,
+    pub feature_levels: Vec<FeatureLevel>
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeviceConfig {
+    pub feature_levels: Vec<FeatureLevel>,
+    pub driver_type: DriverType,
+    pub debug: bool,
+    pub bgra_support: bool,
+    pub fallback: Vec<DriverType>
+}
+impl DeviceConfig {
+    pub const CREATE_DEVICE_DEBUG: u32 = 0x2;
+    pub const CREATE_DEVICE_BGRA_SUPPORT: u32 = 0x20;
+
+    pub fn build() -> Self {
+        Self {
+            feature_levels: vec![FeatureLevel::Level11_0, FeatureLevel::Level10_1, FeatureLevel::Level10_0],
+            driver_type: DriverType::Hardware,
+            debug: false,
+            bgra_support: false,
+            fallback: vec![]
+        }
+    }
+
+    pub fn feature_levels(mut self, feature_levels: &[FeatureLevel]) -> Self {
+        self.feature_levels = feature_levels.to_vec();
+        self
+    }
+
+    pub fn driver_type(mut self, driver_type: DriverType) -> Self {
+        self.driver_type = driver_type;
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    pub fn bgra_support(mut self, bgra_support: bool) -> Self {
+        self.bgra_support = bgra_support;
+        self
+    }
+
+    pub fn fallback(mut self, fallback: &[DriverType]) -> Self {
+        self.fallback = fallback.to_vec();
+        self
+    }
+
+    pub fn flags(&self) -> u32 {
+        let mut flags = 0;
+        if self.debug { flags |= Self::CREATE_DEVICE_DEBUG };
+        if self.bgra_support { flags |= Self::CREATE_DEVICE_BGRA_SUPPORT };
+
+        flags
+    }
+
+    pub fn attempts(&self) -> Vec<DeviceAttempt> {
+        if self.feature_levels.is_empty() {
+            return vec![];
+        }
+
+        let mut driver_types: Vec<DriverType> = vec![];
+        for driver_type in Some(self.driver_type).into_iter().chain(self.fallback.iter().copied()) {
+            if !driver_types.contains(&driver_type) {
+                driver_types.push(driver_type);
+            }
+        }
+
+        let mut flag_sets = vec![self.flags()];
+        if self.debug {
+            flag_sets.push(self.flags() & !Self::CREATE_DEVICE_DEBUG);
+        }
+
+        let mut level_sets = vec![self.feature_levels.clone()];
+        if self.feature_levels.iter().any(|level| *level > FeatureLevel::Level11_0) {
+            let legacy: Vec<FeatureLevel> = self.feature_levels.iter().copied().filter(|level| *level <= FeatureLevel::Level11_0).collect();
+            if !legacy.is_empty() {
+                level_sets.push(legacy);
+            }
+        }
+
+        let mut attempts = vec![];
+        for driver_type in driver_types.iter() {
+            for flags in flag_sets.iter() {
+                for feature_levels in level_sets.iter() {
+                    attempts.push(DeviceAttempt { driver_type: *driver_type, flags: *flags, feature_levels: feature_levels.clone() });
+                }
+            }
+        }
+
+        attempts
+    }
+}
+impl Default for DeviceConfig {
+    fn default() -> Self {
+        Self::build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(attempts: &[DeviceAttempt]) -> Vec<(DriverType, bool, FeatureLevel)> {
+        attempts.iter().map(|attempt| (attempt.driver_type, attempt.flags & DeviceConfig::CREATE_DEVICE_DEBUG != 0, attempt.feature_levels[0])).collect()
+    }
+
+    #[test]
+    fn default_config_tries_once() {
+        let attempts = DeviceConfig::build().attempts();
+
+        assert_eq!(attempts, vec![DeviceAttempt {
+            driver_type: DriverType::Hardware,
+            flags: 0,
+            feature_levels: vec![FeatureLevel::Level11_0, FeatureLevel::Level10_1, FeatureLevel::Level10_0]
+        }]);
+        assert_eq!(DeviceConfig::build().feature_levels(&[]).attempts(), vec![]);
+    }
+
+    #[test]
+    fn orders_driver_debug_and_legacy_levels() {
+        let config = DeviceConfig::build()
+            .feature_levels(&[FeatureLevel::Level11_1, FeatureLevel::Level11_0])
+            .debug(true)
+            .bgra_support(true)
+            .fallback(&[DriverType::Warp, DriverType::Hardware]);
+        let attempts = config.attempts();
+
+        assert_eq!(order(&attempts), vec![
+            (DriverType::Hardware, true, FeatureLevel::Level11_1),
+            (DriverType::Hardware, true, FeatureLevel::Level11_0),
+            (DriverType::Hardware, false, FeatureLevel::Level11_1),
+            (DriverType::Hardware, false, FeatureLevel::Level11_0),
+            (DriverType::Warp, true, FeatureLevel::Level11_1),
+            (DriverType::Warp, true, FeatureLevel::Level11_0),
+            (DriverType::Warp, false, FeatureLevel::Level11_1),
+            (DriverType::Warp, false, FeatureLevel::Level11_0)
+        ]);
+        assert!(attempts.iter().all(|attempt| attempt.flags & DeviceConfig::CREATE_DEVICE_BGRA_SUPPORT != 0));
+        assert_eq!(attempts[1].feature_levels, vec![FeatureLevel::Level11_0]);
+    }
+
+    #[test]
+    fn skips_legacy_retry_without_legacy_levels() {
+        let attempts = DeviceConfig::build().feature_levels(&[FeatureLevel::Level12_1, FeatureLevel::Level12_0]).driver_type(DriverType::Reference).attempts();
+
+        assert_eq!(order(&attempts), vec![(DriverType::Reference, false, FeatureLevel::Level12_1)]);
+        assert_eq!(attempts[0].feature_levels.len(), 2);
+    }
+
+    #[test]
+    fn maps_feature_levels() {
+        assert_eq!(FeatureLevel::from_d3d(FeatureLevel::Level11_1.d3d()), FeatureLevel::Level11_1);
+        assert_eq!(FeatureLevel::from_d3d(0xb080), FeatureLevel::Level11_0);
+        assert_eq!(FeatureLevel::from_d3d(0), FeatureLevel::Level9_1);
+        assert_eq!(FeatureLevel::from_d3d(0xf000), FeatureLevel::Level12_1);
+    }
+}
\ No newline at end of file
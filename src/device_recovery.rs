@@ -0,0 +1,217 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum DeviceRemovedReason {
+    Hung,
+    Removed,
+    Reset,
+    DriverInternalError,
+    InvalidCall,
+    Other(i32)
+}
+impl DeviceRemovedReason {
+    pub const INVALID_CALL: i32 = 0x887a0001u32 as i32;
+    pub const DEVICE_REMOVED: i32 = 0x887a0005u32 as i32;
+    pub const DEVICE_HUNG: i32 = 0x887a0006u32 as i32;
+    pub const DEVICE_RESET: i32 = 0x887a0007u32 as i32;
+    pub const DRIVER_INTERNAL_ERROR: i32 = 0x887a0020u32 as i32;
+
+    pub fn is_device_lost(hr: i32) -> bool {
+        hr == Self::DEVICE_REMOVED || hr == Self::DEVICE_RESET
+    }
+
+    pub fn from_hresult(hr: i32) -> Option<Self> {
+        match hr {
+            0 => None,
+            Self::DEVICE_HUNG => Some(Self::Hung),
+            Self::DEVICE_REMOVED => Some(Self::Removed),
+            Self::DEVICE_RESET => Some(Self::Reset),
+            Self::DRIVER_INTERNAL_ERROR => Some(Self::DriverInternalError),
+            Self::INVALID_CALL => Some(Self::InvalidCall),
+            hr => Some(Self::Other(hr))
+        }
+    }
+
+    pub fn hresult(&self) -> i32 {
+        match self {
+            Self::Hung => Self::DEVICE_HUNG,
+            Self::Removed => Self::DEVICE_REMOVED,
+            Self::Reset => Self::DEVICE_RESET,
+            Self::DriverInternalError => Self::DRIVER_INTERNAL_ERROR,
+            Self::InvalidCall => Self::INVALID_CALL,
+            Self::Other(hr) => *hr
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub struct ResourceId(pub u32);
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ResourceDesc {
+    Shader { vs_bytecode: Vec<u8>, ps_bytecode: Vec<u8> },
+    Buffer { data: Vec<u8>, bind_flags: u32, dynamic: bool },
+    Texture { width: u32, height: u32, format: u32, row_pitch: u32, data: Option<Vec<u8>> },
+    RenderTarget { width: u32, height: u32, format: u32, sample_count: u32 }
+}
+impl ResourceDesc {
+    pub const BIND_VERTEX_BUFFER: u32 = 0x1;
+    pub const BIND_INDEX_BUFFER: u32 = 0x2;
+    pub const BIND_CONSTANT_BUFFER: u32 = 0x4;
+    pub const BIND_SHADER_RESOURCE: u32 = 0x8;
+    pub const BIND_RENDER_TARGET: u32 = 0x20;
+
+    pub fn shader(vs_bytecode: &[u8], ps_bytecode: &[u8]) -> Self {
+        Self::Shader { vs_bytecode: vs_bytecode.to_vec(), ps_bytecode: ps_bytecode.to_vec() }
+    }
+
+    pub fn vertex_buffer(data: &[u8]) -> Self {
+        Self::Buffer { data: data.to_vec(), bind_flags: Self::BIND_VERTEX_BUFFER, dynamic: false }
+    }
+
+    pub fn index_buffer(data: &[u8]) -> Self {
+        Self::Buffer { data: data.to_vec(), bind_flags: Self::BIND_INDEX_BUFFER, dynamic: false }
+    }
+
+    pub fn constant_buffer(data: &[u8]) -> Self {
+        Self::Buffer { data: data.to_vec(), bind_flags: Self::BIND_CONSTANT_BUFFER, dynamic: true }
+    }
+
+    pub fn texture(width: u32, height: u32, format: u32, row_pitch: u32, data: &[u8]) -> Self {
+        Self::Texture { width, height, format, row_pitch, data: Some(data.to_vec()) }
+    }
+
+    pub fn empty_texture(width: u32, height: u32, format: u32) -> Self {
+        Self::Texture { width, height, format, row_pitch: 0, data: None }
+    }
+
+    pub fn render_target(width: u32, height: u32, format: u32) -> Self {
+        Self::RenderTarget { width, height, format, sample_count: 1 }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Shader { vs_bytecode, ps_bytecode } => !vs_bytecode.is_empty() && !ps_bytecode.is_empty(),
+            Self::Buffer { data, .. } => !data.is_empty(),
+            Self::Texture { width, height, row_pitch, data, .. } => {
+                *width > 0 && *height > 0 && match data {
+                    Some(data) => *row_pitch > 0 && data.len() >= (*row_pitch as usize) * (*height as usize),
+                    None => true
+                }
+            },
+            Self::RenderTarget { width, height, sample_count, .. } => *width > 0 && *height > 0 && *sample_count > 0
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ResourceRegistry {
+    pub entries: Vec<(ResourceId, ResourceDesc)>,
+    next_id: u32
+}
+impl ResourceRegistry {
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, desc: ResourceDesc) -> ResourceId {
+        let id = ResourceId(self.next_id);
+        self.next_id += 1;
+        self.entries.push((id, desc));
+
+        id
+    }
+
+    pub fn update(&mut self, id: ResourceId, desc: ResourceDesc) -> bool {
+        match self.entries.iter_mut().find(|(entry, _)| *entry == id) {
+            Some((_, entry)) => {
+                *entry = desc;
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn forget(&mut self, id: ResourceId) -> Option<ResourceDesc> {
+        let index = self.entries.iter().position(|(entry, _)| *entry == id)?;
+
+        Some(self.entries.remove(index).1)
+    }
+
+    pub fn get(&self, id: ResourceId) -> Option<&ResourceDesc> {
+        self.entries.iter().find(|(entry, _)| *entry == id).map(|(_, desc)| desc)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(ResourceId, ResourceDesc)> {
+        self.entries.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_removal_hresults() {
+        assert_eq!(DeviceRemovedReason::from_hresult(0), None);
+
+        for reason in [DeviceRemovedReason::Hung, DeviceRemovedReason::Removed, DeviceRemovedReason::Reset, DeviceRemovedReason::DriverInternalError, DeviceRemovedReason::InvalidCall] {
+            assert_eq!(DeviceRemovedReason::from_hresult(reason.hresult()), Some(reason));
+        }
+
+        let other = 0x80004005u32 as i32;
+        assert_eq!(DeviceRemovedReason::from_hresult(other), Some(DeviceRemovedReason::Other(other)));
+        assert_eq!(DeviceRemovedReason::Other(other).hresult(), other);
+    }
+
+    #[test]
+    fn detects_lost_devices() {
+        assert!(DeviceRemovedReason::is_device_lost(DeviceRemovedReason::DEVICE_REMOVED));
+        assert!(DeviceRemovedReason::is_device_lost(DeviceRemovedReason::DEVICE_RESET));
+        assert!(!DeviceRemovedReason::is_device_lost(DeviceRemovedReason::DEVICE_HUNG));
+        assert!(!DeviceRemovedReason::is_device_lost(0));
+    }
+
+    #[test]
+    fn validates_descriptions() {
+        assert!(ResourceDesc::vertex_buffer(&[0; 16]).is_valid());
+        assert!(!ResourceDesc::vertex_buffer(&[]).is_valid());
+        assert!(!ResourceDesc::shader(&[1], &[]).is_valid());
+        assert!(ResourceDesc::texture(2, 2, 28, 8, &[0; 16]).is_valid());
+        assert!(!ResourceDesc::texture(2, 2, 28, 8, &[0; 15]).is_valid());
+        assert!(!ResourceDesc::texture(2, 2, 28, 0, &[0; 16]).is_valid());
+        assert!(ResourceDesc::empty_texture(2, 2, 28).is_valid());
+        assert!(!ResourceDesc::render_target(0, 2, 28).is_valid());
+        assert!(!ResourceDesc::RenderTarget { width: 2, height: 2, format: 28, sample_count: 0 }.is_valid());
+    }
+
+    #[test]
+    fn records_updates_and_forgets() {
+        let mut registry = ResourceRegistry::build();
+        let first = registry.record(ResourceDesc::vertex_buffer(&[1]));
+        let second = registry.record(ResourceDesc::index_buffer(&[2]));
+        assert_eq!((first, second), (ResourceId(0), ResourceId(1)));
+        assert_eq!(registry.len(), 2);
+
+        assert!(registry.update(first, ResourceDesc::constant_buffer(&[3])));
+        assert_eq!(registry.get(first), Some(&ResourceDesc::constant_buffer(&[3])));
+        assert!(!registry.update(ResourceId(7), ResourceDesc::vertex_buffer(&[4])));
+
+        assert_eq!(registry.forget(first), Some(ResourceDesc::constant_buffer(&[3])));
+        assert_eq!(registry.forget(first), None);
+        assert_eq!(registry.get(first), None);
+        assert_eq!(registry.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![second]);
+
+        let third = registry.record(ResourceDesc::vertex_buffer(&[5]));
+        assert_eq!(third, ResourceId(2));
+        registry.forget(second);
+        registry.forget(third);
+        assert!(registry.is_empty());
+    }
+}
\ No newline at end of file
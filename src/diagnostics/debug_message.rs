@@ -0,0 +1,267 @@
+use crate::dependencies::{ Arc, Sender };
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum MessageSeverity {
+    Corruption,
+    Error,
+    Warning,
+    Info,
+    Message
+}
+impl MessageSeverity {
+    pub fn from_d3d(severity: u32) -> Self {
+        match severity {
+            0 => Self::Corruption,
+            1 => Self::Error,
+            2 => Self::Warning,
+            3 => Self::Info,
+            _ => Self::Message
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum MessageCategory {
+    ApplicationDefined,
+    Miscellaneous,
+    Initialization,
+    Cleanup,
+    Compilation,
+    StateCreation,
+    StateSetting,
+    StateGetting,
+    ResourceManipulation,
+    Execution,
+    Shader,
+    Other(u32)
+}
+impl MessageCategory {
+    pub fn from_d3d(category: u32) -> Self {
+        match category {
+            0 => Self::ApplicationDefined,
+            1 => Self::Miscellaneous,
+            2 => Self::Initialization,
+            3 => Self::Cleanup,
+            4 => Self::Compilation,
+            5 => Self::StateCreation,
+            6 => Self::StateSetting,
+            7 => Self::StateGetting,
+            8 => Self::ResourceManipulation,
+            9 => Self::Execution,
+            10 => Self::Shader,
+            category => Self::Other(category)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DebugMessage {
+    pub severity: MessageSeverity,
+    pub category: MessageCategory,
+    pub id: i32,
+    pub text: String
+}
+impl DebugMessage {
+    pub fn from_d3d(severity: u32, category: u32, id: i32, text: &[u8]) -> Self {
+        let length = text.iter().position(|byte| *byte == 0).unwrap_or(text.len());
+
+        Self {
+            severity: MessageSeverity::from_d3d(severity),
+            category: MessageCategory::from_d3d(category),
+            id,
+            text: String::from_utf8_lossy(&text[..length]).into_owned()
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct MessageFilter {
+    pub severity: MessageSeverity,
+    pub categories: Option<Vec<MessageCategory>>,
+    pub denied_ids: Vec<i32>
+}
+impl MessageFilter {
+    pub fn build() -> Self {
+        Self { severity: MessageSeverity::Message, categories: None, denied_ids: vec![] }
+    }
+
+    pub fn at_least(mut self, severity: MessageSeverity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn categories(mut self, categories: &[MessageCategory]) -> Self {
+        self.categories = Some(categories.to_vec());
+        self
+    }
+
+    pub fn deny(mut self, id: i32) -> Self {
+        self.denied_ids.push(id);
+        self
+    }
+
+    pub fn allows(&self, message: &DebugMessage) -> bool {
+        if message.severity > self.severity {
+            return false;
+        }
+        if let Some(categories) = self.categories.as_ref() && !categories.contains(&message.category) {
+            return false;
+        }
+
+        !self.denied_ids.contains(&message.id)
+    }
+}
+impl Default for MessageFilter {
+    fn default() -> Self {
+        Self::build()
+    }
+}
+
+#[derive(Clone)]
+pub enum MessageSink {
+    Callback(Arc<dyn Fn(&DebugMessage) + Send + Sync>),
+    Channel(Sender<DebugMessage>)
+}
+impl MessageSink {
+    pub fn callback<F: Fn(&DebugMessage) + Send + Sync + 'static>(callback: F) -> Self {
+        Self::Callback(Arc::new(callback))
+    }
+
+    pub fn send(&self, message: &DebugMessage) -> bool {
+        match self {
+            Self::Callback(callback) => {
+                callback(message);
+                true
+            },
+            Self::Channel(sender) => sender.send(message.clone()).is_ok()
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct MessageDispatcher {
+    pub filter: MessageFilter,
+    pub sinks: Vec<MessageSink>
+}
+impl MessageDispatcher {
+    pub fn build(filter: MessageFilter) -> Self {
+        Self { filter, sinks: vec![] }
+    }
+
+    pub fn add_sink(&mut self, sink: MessageSink) {
+        self.sinks.push(sink);
+    }
+
+    pub fn dispatch(&mut self, messages: Vec<DebugMessage>) -> Vec<DebugMessage> {
+        let messages: Vec<DebugMessage> = messages.into_iter().filter(|message| self.filter.allows(message)).collect();
+
+        for message in messages.iter() {
+            self.sinks.retain(|sink| sink.send(message));
+        }
+
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependencies::{ channel, Mutex };
+
+    fn message(severity: MessageSeverity, category: MessageCategory, id: i32) -> DebugMessage {
+        DebugMessage { severity, category, id, text: format!("message {}", id) }
+    }
+
+    #[test]
+    fn converts_d3d_messages() {
+        let message = DebugMessage::from_d3d(1, 5, 42, b"invalid state\0garbage");
+
+        assert_eq!(message, DebugMessage {
+            severity: MessageSeverity::Error,
+            category: MessageCategory::StateCreation,
+            id: 42,
+            text: "invalid state".to_string()
+        });
+        assert_eq!(MessageSeverity::from_d3d(9), MessageSeverity::Message);
+        assert_eq!(MessageCategory::from_d3d(11), MessageCategory::Other(11));
+    }
+
+    #[test]
+    fn filters_by_severity() {
+        let filter = MessageFilter::build().at_least(MessageSeverity::Warning);
+
+        assert!(filter.allows(&message(MessageSeverity::Corruption, MessageCategory::Execution, 1)));
+        assert!(filter.allows(&message(MessageSeverity::Error, MessageCategory::Execution, 1)));
+        assert!(filter.allows(&message(MessageSeverity::Warning, MessageCategory::Execution, 1)));
+        assert!(!filter.allows(&message(MessageSeverity::Info, MessageCategory::Execution, 1)));
+        assert!(!filter.allows(&message(MessageSeverity::Message, MessageCategory::Execution, 1)));
+        assert!(MessageFilter::default().allows(&message(MessageSeverity::Message, MessageCategory::Execution, 1)));
+    }
+
+    #[test]
+    fn filters_by_category() {
+        let filter = MessageFilter::build().categories(&[MessageCategory::StateSetting, MessageCategory::Other(20)]);
+
+        assert!(filter.allows(&message(MessageSeverity::Error, MessageCategory::StateSetting, 1)));
+        assert!(filter.allows(&message(MessageSeverity::Error, MessageCategory::Other(20), 1)));
+        assert!(!filter.allows(&message(MessageSeverity::Error, MessageCategory::Other(21), 1)));
+        assert!(!filter.allows(&message(MessageSeverity::Error, MessageCategory::Shader, 1)));
+        assert!(!MessageFilter::build().categories(&[]).allows(&message(MessageSeverity::Error, MessageCategory::Shader, 1)));
+    }
+
+    #[test]
+    fn filters_denied_ids() {
+        let filter = MessageFilter::build().deny(7).deny(9);
+
+        assert!(!filter.allows(&message(MessageSeverity::Corruption, MessageCategory::Execution, 7)));
+        assert!(!filter.allows(&message(MessageSeverity::Error, MessageCategory::Execution, 9)));
+        assert!(filter.allows(&message(MessageSeverity::Error, MessageCategory::Execution, 8)));
+    }
+
+    #[test]
+    fn dispatches_allowed_messages_to_sinks() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let callback_received = received.clone();
+        let (sender, receiver) = channel();
+
+        let mut dispatcher = MessageDispatcher::build(MessageFilter::build().at_least(MessageSeverity::Warning).deny(3));
+        dispatcher.add_sink(MessageSink::callback(move |message| callback_received.lock().unwrap().push(message.id)));
+        dispatcher.add_sink(MessageSink::Channel(sender));
+
+        let dispatched = dispatcher.dispatch(vec![
+            message(MessageSeverity::Error, MessageCategory::Execution, 1),
+            message(MessageSeverity::Info, MessageCategory::Execution, 2),
+            message(MessageSeverity::Error, MessageCategory::Execution, 3),
+            message(MessageSeverity::Warning, MessageCategory::Shader, 4)
+        ]);
+
+        assert_eq!(dispatched.iter().map(|message| message.id).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(*received.lock().unwrap(), vec![1, 4]);
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), dispatched);
+        assert_eq!(dispatcher.sinks.len(), 2);
+    }
+
+    #[test]
+    fn removes_disconnected_channel_sinks() {
+        let (sender, receiver) = channel();
+        let (dropped_sender, dropped_receiver) = channel();
+        drop(dropped_receiver);
+
+        let mut dispatcher = MessageDispatcher::default();
+        dispatcher.add_sink(MessageSink::Channel(dropped_sender));
+        dispatcher.add_sink(MessageSink::Channel(sender));
+
+        assert!(dispatcher.dispatch(vec![]).is_empty());
+        assert_eq!(dispatcher.sinks.len(), 2);
+
+        dispatcher.dispatch(vec![message(MessageSeverity::Error, MessageCategory::Execution, 1)]);
+        assert_eq!(dispatcher.sinks.len(), 1);
+
+        dispatcher.dispatch(vec![message(MessageSeverity::Error, MessageCategory::Execution, 2)]);
+        assert_eq!(receiver.try_iter().map(|message| message.id).collect::<Vec<_>>(), vec![1, 2]);
+
+        drop(receiver);
+        dispatcher.dispatch(vec![message(MessageSeverity::Error, MessageCategory::Execution, 3)]);
+        assert!(dispatcher.sinks.is_empty());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,84 @@
+use crate::dependencies::{
+    null_mut, size_of, from_raw_parts, SUCCEEDED, ID3D11Device, ID3D11InfoQueue, D3D11_MESSAGE
+};
+
+use crate::{ ComPtr, DebugMessage, MessageDispatcher, MessageFilter, MessageSink };
+
+#[derive(Clone)]
+pub struct InfoQueue {
+    pub queue: ComPtr<ID3D11InfoQueue>,
+    pub dispatcher: MessageDispatcher,
+    pub read: u64
+}
+impl InfoQueue {
+    pub fn create(device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        let queue = match device.cast::<ID3D11InfoQueue>() {
+            Some(queue) => queue,
+            None => return None
+        };
+
+        Some(Self { queue, dispatcher: MessageDispatcher::default(), read: 0 })
+    }
+
+    pub fn rebind(self, device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        match Self::create(device) {
+            Some(queue) => Some(Self { dispatcher: self.dispatcher, ..queue }),
+            None => None
+        }
+    }
+
+    pub fn set_filter(&mut self, filter: MessageFilter) {
+        self.dispatcher.filter = filter;
+    }
+
+    pub fn add_sink(&mut self, sink: MessageSink) {
+        self.dispatcher.add_sink(sink);
+    }
+
+    pub fn poll(&mut self) -> Vec<DebugMessage> {
+        let messages = self.drain();
+
+        self.dispatcher.dispatch(messages)
+    }
+
+    pub fn drain(&mut self) -> Vec<DebugMessage> {
+        let discarded = unsafe { self.queue.GetNumMessagesDiscardedByMessageCountLimit() };
+        let count = unsafe { self.queue.GetNumStoredMessages() };
+
+        let start = match discarded + count < self.read {
+            true => 0,
+            false => self.read.saturating_sub(discarded)
+        };
+
+        let mut messages = vec![];
+        for index in start..count {
+            if let Some(message) = self.get_message(index) {
+                messages.push(message);
+            }
+        }
+        self.read = discarded + count;
+
+        messages
+    }
+
+    fn get_message(&self, index: u64) -> Option<DebugMessage> {
+        let mut length = 0;
+        let hr = unsafe { self.queue.GetMessage(index, null_mut(), &mut length) };
+        if !SUCCEEDED(hr) || length < size_of::<D3D11_MESSAGE>() { return None };
+
+        let mut buffer = vec![0u64; length.div_ceil(size_of::<u64>())];
+        let message = buffer.as_mut_ptr() as *mut D3D11_MESSAGE;
+        let hr = unsafe { self.queue.GetMessage(index, message, &mut length) };
+        if !SUCCEEDED(hr) { return None };
+
+        let message = unsafe { &*message };
+        let text = match message.pDescription.is_null() {
+            true => &[][..],
+            false => unsafe { from_raw_parts(message.pDescription as *const u8, message.DescriptionByteLength) }
+        };
+
+        Some(DebugMessage::from_d3d(message.Severity, message.Category, message.ID as i32, text))
+    }
+}
+unsafe impl Send for InfoQueue {}
+unsafe impl Sync for InfoQueue {}
\ No newline at end of file
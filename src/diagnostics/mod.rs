@@ -0,0 +1,7 @@
+mod debug_message;
+pub use debug_message::{ DebugMessage, MessageSeverity, MessageCategory, MessageFilter, MessageSink, MessageDispatcher };
+
+#[cfg(windows)]
+mod info_queue;
+#[cfg(windows)]
+pub use info_queue::InfoQueue;
\ No newline at end of file
@@ -0,0 +1,164 @@
+use crate::dependencies::{
+    null_mut, zeroed, size_of, SUCCEEDED, Interface, BOOL, FALSE, IDXGIFactory1, IDXGIFactory5, DXGI_FEATURE_PRESENT_ALLOW_TEARING, IDXGIAdapter1, IDXGIOutput, DXGI_ADAPTER_DESC1, DXGI_OUTPUT_DESC, DXGI_MODE_DESC, DXGI_ADAPTER_FLAG_SOFTWARE, DXGI_FORMAT_R8G8B8A8_UNORM, DISPLAY_DEVICEW, CreateDXGIFactory1, EnumDisplayDevicesW
+};
+
+use crate::{ ComPtr, AdapterInfo, AdapterPreference, OutputInfo, DisplayMode, ScreenRect };
+
+fn from_wide(value: &[u16]) -> String {
+    let length = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+
+    String::from_utf16_lossy(&value[..length])
+}
+
+#[derive(Clone)]
+pub struct Adapter {
+    pub adapter: ComPtr<IDXGIAdapter1>,
+    pub info: AdapterInfo
+}
+impl Adapter {
+    pub fn enumerate() -> Vec<Self> {
+        let factory = match Self::create_factory() {
+            Some(factory) => factory,
+            None => return vec![]
+        };
+
+        let mut adapters = vec![];
+        for index in 0.. {
+            let mut adapter = null_mut::<IDXGIAdapter1>();
+            let hr = unsafe { factory.EnumAdapters1(index, &mut adapter) };
+            if !SUCCEEDED(hr) { break };
+
+            let adapter = match unsafe { ComPtr::from_raw(adapter) } {
+                Some(adapter) => adapter,
+                None => break
+            };
+            if let Some(info) = Self::describe(&adapter, index) {
+                adapters.push(Self { adapter, info });
+            }
+        }
+
+        adapters
+    }
+
+    pub fn select(preference: AdapterPreference) -> Option<Self> {
+        let mut adapters = Self::enumerate();
+        let infos: Vec<AdapterInfo> = adapters.iter().map(|adapter| adapter.info.clone()).collect();
+
+        preference.select(&infos).map(|index| adapters.swap_remove(index))
+    }
+
+    pub fn infos() -> Vec<AdapterInfo> {
+        Self::enumerate().into_iter().map(|adapter| adapter.info).collect()
+    }
+
+    pub fn describe(adapter: &ComPtr<IDXGIAdapter1>, index: u32) -> Option<AdapterInfo> {
+        let mut desc = unsafe { zeroed::<DXGI_ADAPTER_DESC1>() };
+        let hr = unsafe { adapter.GetDesc1(&mut desc) };
+        if !SUCCEEDED(hr) { return None };
+
+        Some(AdapterInfo {
+            index,
+            description: from_wide(&desc.Description),
+            vendor_id: desc.VendorId,
+            device_id: desc.DeviceId,
+            subsystem_id: desc.SubSysId,
+            revision: desc.Revision,
+            luid: ((desc.AdapterLuid.HighPart as u32 as u64) << 32) | desc.AdapterLuid.LowPart as u64,
+            dedicated_video_memory: desc.DedicatedVideoMemory as u64,
+            dedicated_system_memory: desc.DedicatedSystemMemory as u64,
+            shared_system_memory: desc.SharedSystemMemory as u64,
+            software: desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE != 0,
+            outputs: Self::outputs(adapter)
+        })
+    }
+
+    pub fn supports_tearing() -> bool {
+        let factory = match Self::create_factory().and_then(|factory| factory.cast::<IDXGIFactory5>()) {
+            Some(factory) => factory,
+            None => return false
+        };
+
+        let mut allow_tearing: BOOL = FALSE;
+        let hr = unsafe { factory.CheckFeatureSupport(
+            DXGI_FEATURE_PRESENT_ALLOW_TEARING,
+            &mut allow_tearing as *mut _ as *mut _,
+            size_of::<BOOL>() as u32
+        ) };
+
+        SUCCEEDED(hr) && allow_tearing != FALSE
+    }
+
+    fn create_factory() -> Option<ComPtr<IDXGIFactory1>> {
+        let mut factory = null_mut::<IDXGIFactory1>();
+        let hr = unsafe { CreateDXGIFactory1(&IDXGIFactory1::uuidof(), &mut factory as *mut _ as *mut *mut _) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(factory) } };
+
+        None
+    }
+
+    fn outputs(adapter: &ComPtr<IDXGIAdapter1>) -> Vec<OutputInfo> {
+        let mut outputs = vec![];
+        for index in 0.. {
+            let mut output = null_mut::<IDXGIOutput>();
+            let hr = unsafe { adapter.EnumOutputs(index, &mut output) };
+            if !SUCCEEDED(hr) { break };
+
+            let output = match unsafe { ComPtr::from_raw(output) } {
+                Some(output) => output,
+                None => break
+            };
+            if let Some(info) = Self::output_info(&output) {
+                outputs.push(info);
+            }
+        }
+
+        outputs
+    }
+
+    fn output_info(output: &ComPtr<IDXGIOutput>) -> Option<OutputInfo> {
+        let mut desc = unsafe { zeroed::<DXGI_OUTPUT_DESC>() };
+        let hr = unsafe { output.GetDesc(&mut desc) };
+        if !SUCCEEDED(hr) { return None };
+
+        let rect = desc.DesktopCoordinates;
+
+        Some(OutputInfo {
+            device_name: from_wide(&desc.DeviceName),
+            monitor_name: Self::monitor_name(&desc.DeviceName),
+            desktop: ScreenRect::from_edges(rect.left, rect.top, rect.right, rect.bottom),
+            attached: desc.AttachedToDesktop != 0,
+            rotation: desc.Rotation,
+            modes: Self::display_modes(output)
+        })
+    }
+
+    fn monitor_name(device_name: &[u16]) -> String {
+        let mut device = unsafe { zeroed::<DISPLAY_DEVICEW>() };
+        device.cb = size_of::<DISPLAY_DEVICEW>() as u32;
+
+        match unsafe { EnumDisplayDevicesW(device_name.as_ptr(), 0, &mut device, 0) } {
+            0 => String::new(),
+            _ => from_wide(&device.DeviceString)
+        }
+    }
+
+    fn display_modes(output: &ComPtr<IDXGIOutput>) -> Vec<DisplayMode> {
+        let mut count = 0;
+        let hr = unsafe { output.GetDisplayModeList(DXGI_FORMAT_R8G8B8A8_UNORM, 0, &mut count, null_mut()) };
+        if !SUCCEEDED(hr) || count == 0 { return vec![] };
+
+        let mut modes = vec![unsafe { zeroed::<DXGI_MODE_DESC>() }; count as usize];
+        let hr = unsafe { output.GetDisplayModeList(DXGI_FORMAT_R8G8B8A8_UNORM, 0, &mut count, modes.as_mut_ptr()) };
+        if !SUCCEEDED(hr) { return vec![] };
+
+        modes.iter().take(count as usize).map(|mode| DisplayMode {
+            width: mode.Width,
+            height: mode.Height,
+            refresh_rate: [mode.RefreshRate.Numerator, mode.RefreshRate.Denominator],
+            format: mode.Format
+        }).collect()
+    }
+}
+unsafe impl Send for Adapter {}
+unsafe impl Sync for Adapter {}
\ No newline at end of file
@@ -1,17 +1,18 @@
 use crate::dependencies::{
-    null_mut, zeroed, D3D11CreateDeviceAndSwapChain, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, IDXGISwapChain, HWND, Interface, DXGI_SWAP_CHAIN_DESC, DXGI_MODE_DESC, DXGI_RATIONAL, DXGI_SAMPLE_DESC, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED, DXGI_MODE_SCALING_UNSPECIFIED, DXGI_USAGE_RENDER_TARGET_OUTPUT, DXGI_SWAP_EFFECT_DISCARD, DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH, D3D_FEATURE_LEVEL_10_1, D3D_FEATURE_LEVEL_11_0, D3D_DRIVER_TYPE_HARDWARE, D3D11_SDK_VERSION
+    null_mut, zeroed, D3D11CreateDeviceAndSwapChain, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, IDXGISwapChain, HWND, Interface, DXGI_SWAP_CHAIN_DESC, DXGI_MODE_DESC, DXGI_RATIONAL, DXGI_SAMPLE_DESC, DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED, DXGI_MODE_SCALING_UNSPECIFIED, DXGI_USAGE_RENDER_TARGET_OUTPUT, D3D_FEATURE_LEVEL, D3D_DRIVER_TYPE_UNKNOWN, D3D11_SDK_VERSION, IDXGIAdapter
 };
 
-use crate::{ WindowHandle };
+use crate::{ WindowHandle, ComPtr, SwapchainConfig, DeviceConfig, DeviceAttempt, DriverType, FeatureLevel, Adapter };
 
 #[derive(Clone)]
 pub struct Direct3D {
-    pub device: *mut ID3D11Device,
-    pub context: *mut ID3D11DeviceContext,
-    pub backbuffer: *mut ID3D11Texture2D,
+    pub device: ComPtr<ID3D11Device>,
+    pub context: ComPtr<ID3D11DeviceContext>,
+    pub backbuffer: Option<ComPtr<ID3D11Texture2D>>,
     pub desc: DXGI_SWAP_CHAIN_DESC,
     pub hwnd: HWND,
-    pub resolution: [u32; 2]
+    pub resolution: [u32; 2],
+    pub feature_level: FeatureLevel
 }
 impl Direct3D {
     pub fn get(swapchain: *mut IDXGISwapChain) -> Option<Self> {
@@ -19,9 +20,12 @@ impl Direct3D {
             Some(device) => device,
             None => return None
         };
-        let context = Self::get_context(device);
+        let context = match Self::get_context(&device) {
+            Some(context) => context,
+            None => return None
+        };
         let backbuffer = match Self::get_backbuffer(swapchain) {
-            Some(backbuffer) => backbuffer,
+            Some(backbuffer) => Some(backbuffer),
             None => return None
         };
         let desc = match Self::get_desc(swapchain) {
@@ -30,83 +34,120 @@ impl Direct3D {
         };
         let hwnd = Self::get_hwnd(desc);
         let resolution = Self::get_resolution(desc);
+        let feature_level = FeatureLevel::from_d3d(unsafe { device.GetFeatureLevel() });
+
+        Some(Self { device, context, backbuffer, desc, hwnd, resolution, feature_level })
+    }
 
-        Some(Self { device, context, backbuffer, desc, hwnd, resolution })
+    pub fn release_backbuffer(&mut self) {
+        self.backbuffer = None;
     }
 
-    pub fn create_device_and_swapchain(window_handle: &WindowHandle) -> Option<(*mut IDXGISwapChain, *mut ID3D11Device, *mut ID3D11DeviceContext)> {
+    pub fn acquire_backbuffer(&mut self, swapchain: *mut IDXGISwapChain) -> bool {
+        let desc = match Self::get_desc(swapchain) {
+            Some(desc) => desc,
+            None => return false
+        };
+
+        self.backbuffer = Self::get_backbuffer(swapchain);
+        self.desc = desc;
+        self.resolution = Self::get_resolution(desc);
+
+        self.backbuffer.is_some()
+    }
+
+    pub fn create_device_and_swapchain(window_handle: &WindowHandle, config: &SwapchainConfig, device_config: &DeviceConfig, adapter: Option<&Adapter>) -> Option<(ComPtr<IDXGISwapChain>, ComPtr<ID3D11Device>, ComPtr<ID3D11DeviceContext>)> {
         let rect = match window_handle.get_rect() {
             Some(rect) => rect,
             None => return None
         };
-        let feature_levels = [D3D_FEATURE_LEVEL_10_1, D3D_FEATURE_LEVEL_11_0];
-        let mut feature_level = D3D_FEATURE_LEVEL_11_0;
+        let hwnd = match window_handle.hwnd {
+            Some(hwnd) => hwnd,
+            None => return None
+        };
+        let desc = DXGI_SWAP_CHAIN_DESC {
+            BufferDesc: DXGI_MODE_DESC {
+                Width: (rect.right - rect.left) as u32,
+                Height: (rect.bottom - rect.top) as u32,
+                RefreshRate: DXGI_RATIONAL {
+                    Numerator: config.refresh_rate[0],
+                    Denominator: config.refresh_rate[1]
+                },
+                Format: config.buffer_format().rtv_format(),
+                ScanlineOrdering: DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED,
+                Scaling: DXGI_MODE_SCALING_UNSPECIFIED
+            },
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: config.sample_count,
+                Quality: 0
+            },
+            BufferUsage: DXGI_USAGE_RENDER_TARGET_OUTPUT,
+            BufferCount: config.buffer_count,
+            OutputWindow: hwnd,
+            Windowed: 1,
+            SwapEffect: config.swap_effect.dxgi(),
+            Flags: config.flags()
+        };
+
+        device_config.attempts().iter().find_map(|attempt| Self::try_create(&desc, attempt, adapter))
+    }
+
+    fn try_create(desc: &DXGI_SWAP_CHAIN_DESC, attempt: &DeviceAttempt, adapter: Option<&Adapter>) -> Option<(ComPtr<IDXGISwapChain>, ComPtr<ID3D11Device>, ComPtr<ID3D11DeviceContext>)> {
+        let feature_levels: Vec<D3D_FEATURE_LEVEL> = attempt.feature_levels.iter().map(|level| level.d3d()).collect();
+        let mut feature_level = 0;
         let mut swapchain = null_mut::<IDXGISwapChain>();
         let mut device = null_mut::<ID3D11Device>();
         let mut context = null_mut::<ID3D11DeviceContext>();
 
+        let (adapter, driver_type) = match (adapter, attempt.driver_type) {
+            (Some(adapter), DriverType::Hardware) => (adapter.adapter.as_ptr() as *mut IDXGIAdapter, D3D_DRIVER_TYPE_UNKNOWN),
+            (_, driver_type) => (null_mut(), driver_type.d3d())
+        };
+
         let hr = unsafe { D3D11CreateDeviceAndSwapChain(
+            adapter,
+            driver_type,
             null_mut(),
-            D3D_DRIVER_TYPE_HARDWARE,
-            null_mut(),
-            0,
+            attempt.flags,
             feature_levels.as_ptr(),
             feature_levels.len() as u32,
             D3D11_SDK_VERSION,
-            &DXGI_SWAP_CHAIN_DESC {
-                BufferDesc: DXGI_MODE_DESC {
-                    Width: (rect.right - rect.left) as u32,
-                    Height: (rect.bottom - rect.top) as u32,
-                    RefreshRate: DXGI_RATIONAL {
-                        Numerator: 60,
-                        Denominator: 1
-                    },
-                    Format: DXGI_FORMAT_R8G8B8A8_UNORM,
-                    ScanlineOrdering: DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED,
-                    Scaling: DXGI_MODE_SCALING_UNSPECIFIED
-                },
-                SampleDesc: DXGI_SAMPLE_DESC {
-                    Count: 1,
-                    Quality: 0
-                },
-                BufferUsage: DXGI_USAGE_RENDER_TARGET_OUTPUT,
-                BufferCount: 1,
-                OutputWindow: window_handle.hwnd.unwrap(),
-                Windowed: 1,
-                SwapEffect: DXGI_SWAP_EFFECT_DISCARD,
-                Flags: DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH
-            },
+            desc,
             &mut swapchain,
             &mut device,
             &mut feature_level,
             &mut context
         ) };
 
-        if SUCCEEDED(hr) { return Some((swapchain, device, context)) }
+        if !SUCCEEDED(hr) { return None };
 
-        None
+        let swapchain = unsafe { ComPtr::from_raw(swapchain) }?;
+        let device = unsafe { ComPtr::from_raw(device) }?;
+        let context = unsafe { ComPtr::from_raw(context) }?;
+
+        Some((swapchain, device, context))
     }
 
-    fn get_device(swapchain: *mut IDXGISwapChain) -> Option<*mut ID3D11Device> {
+    fn get_device(swapchain: *mut IDXGISwapChain) -> Option<ComPtr<ID3D11Device>> {
         let mut device = null_mut::<ID3D11Device>();
         let hr = unsafe { (*swapchain).GetDevice(
             &ID3D11Device::uuidof(),
             &mut device as *mut _ as *mut *mut _,
         ) };
 
-        if SUCCEEDED(hr) { return Some(device) };
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(device) } };
 
         None
     }
 
-    fn get_context(device: *mut ID3D11Device) -> *mut ID3D11DeviceContext {
+    fn get_context(device: &ComPtr<ID3D11Device>) -> Option<ComPtr<ID3D11DeviceContext>> {
         let mut context: *mut ID3D11DeviceContext = null_mut();
-        unsafe { (*device).GetImmediateContext(&mut context) }
+        unsafe { device.GetImmediateContext(&mut context) }
 
-        context
+        unsafe { ComPtr::from_raw(context) }
     }
 
-    fn get_backbuffer(swapchain: *mut IDXGISwapChain) -> Option<*mut ID3D11Texture2D> {
+    fn get_backbuffer(swapchain: *mut IDXGISwapChain) -> Option<ComPtr<ID3D11Texture2D>> {
         let mut backbuffer = null_mut::<ID3D11Texture2D>();
         let hr = unsafe { (*swapchain).GetBuffer(
             0,
@@ -114,7 +155,7 @@ impl Direct3D {
             &mut backbuffer as *mut _ as *mut *mut _,
         ) };
 
-        if SUCCEEDED(hr) { return Some(backbuffer) };
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(backbuffer) } };
 
         None
     }
@@ -0,0 +1,202 @@
+use crate::dependencies::{
+    null, null_mut, HashMap, SUCCEEDED, ID3D11Device, ID3D11Buffer, ID3D11Texture2D, ID3D11Resource, ID3D11ShaderResourceView, ID3D11RenderTargetView, D3D11_BUFFER_DESC, D3D11_TEXTURE2D_DESC, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT, D3D11_USAGE_DYNAMIC, D3D11_CPU_ACCESS_WRITE, D3D11_BIND_SHADER_RESOURCE, D3D11_BIND_RENDER_TARGET, DXGI_SAMPLE_DESC
+};
+
+use crate::{ ComPtr, Shader, ResourceId, ResourceDesc, ResourceRegistry };
+
+#[derive(Clone)]
+pub enum DeviceResource {
+    Shader(Box<Shader>),
+    Buffer(ComPtr<ID3D11Buffer>),
+    Texture { texture: ComPtr<ID3D11Texture2D>, view: ComPtr<ID3D11ShaderResourceView> },
+    RenderTarget { texture: ComPtr<ID3D11Texture2D>, view: ComPtr<ID3D11RenderTargetView>, shader_view: ComPtr<ID3D11ShaderResourceView> }
+}
+impl DeviceResource {
+    pub fn create(device: &ComPtr<ID3D11Device>, desc: &ResourceDesc) -> Option<Self> {
+        if !desc.is_valid() { return None };
+
+        match desc {
+            ResourceDesc::Shader { vs_bytecode, ps_bytecode } => Shader::from_bytecode(vs_bytecode, ps_bytecode, device).map(|shader| Self::Shader(Box::new(shader))),
+            ResourceDesc::Buffer { data, bind_flags, dynamic } => Self::create_buffer(device, data, *bind_flags, *dynamic).map(Self::Buffer),
+            ResourceDesc::Texture { width, height, format, row_pitch, data } => {
+                let initial_data = data.as_ref().map(|data| D3D11_SUBRESOURCE_DATA {
+                    pSysMem: data.as_ptr() as *const _,
+                    SysMemPitch: *row_pitch,
+                    SysMemSlicePitch: 0
+                });
+                let texture = Self::create_texture(device, [*width, *height], *format, 1, D3D11_BIND_SHADER_RESOURCE, initial_data.as_ref())?;
+                let view = Self::create_shader_view(device, &texture)?;
+
+                Some(Self::Texture { texture, view })
+            },
+            ResourceDesc::RenderTarget { width, height, format, sample_count } => {
+                let texture = Self::create_texture(device, [*width, *height], *format, *sample_count, D3D11_BIND_RENDER_TARGET | D3D11_BIND_SHADER_RESOURCE, None)?;
+                let view = Self::create_render_view(device, &texture)?;
+                let shader_view = Self::create_shader_view(device, &texture)?;
+
+                Some(Self::RenderTarget { texture, view, shader_view })
+            }
+        }
+    }
+
+    pub fn shader(&self) -> Option<&Shader> {
+        match self {
+            Self::Shader(shader) => Some(shader),
+            _ => None
+        }
+    }
+
+    pub fn buffer(&self) -> Option<&ComPtr<ID3D11Buffer>> {
+        match self {
+            Self::Buffer(buffer) => Some(buffer),
+            _ => None
+        }
+    }
+
+    pub fn shader_view(&self) -> Option<&ComPtr<ID3D11ShaderResourceView>> {
+        match self {
+            Self::Texture { view, .. } => Some(view),
+            Self::RenderTarget { shader_view, .. } => Some(shader_view),
+            _ => None
+        }
+    }
+
+    pub fn render_view(&self) -> Option<&ComPtr<ID3D11RenderTargetView>> {
+        match self {
+            Self::RenderTarget { view, .. } => Some(view),
+            _ => None
+        }
+    }
+
+    fn create_buffer(device: &ComPtr<ID3D11Device>, data: &[u8], bind_flags: u32, dynamic: bool) -> Option<ComPtr<ID3D11Buffer>> {
+        let desc = D3D11_BUFFER_DESC {
+            ByteWidth: data.len() as u32,
+            Usage: if dynamic { D3D11_USAGE_DYNAMIC } else { D3D11_USAGE_DEFAULT },
+            BindFlags: bind_flags,
+            CPUAccessFlags: if dynamic { D3D11_CPU_ACCESS_WRITE } else { 0 },
+            MiscFlags: 0,
+            StructureByteStride: 0
+        };
+
+        let initial_data = D3D11_SUBRESOURCE_DATA {
+            pSysMem: data.as_ptr() as *const _,
+            SysMemPitch: 0,
+            SysMemSlicePitch: 0
+        };
+
+        let mut buffer = null_mut::<ID3D11Buffer>();
+        let hr = unsafe { device.CreateBuffer(&desc, &initial_data, &mut buffer) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(buffer) } };
+
+        None
+    }
+
+    fn create_texture(device: &ComPtr<ID3D11Device>, size: [u32; 2], format: u32, sample_count: u32, bind_flags: u32, initial_data: Option<&D3D11_SUBRESOURCE_DATA>) -> Option<ComPtr<ID3D11Texture2D>> {
+        let desc = D3D11_TEXTURE2D_DESC {
+            Width: size[0],
+            Height: size[1],
+            MipLevels: 1,
+            ArraySize: 1,
+            Format: format,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: sample_count,
+                Quality: 0
+            },
+            Usage: D3D11_USAGE_DEFAULT,
+            BindFlags: bind_flags,
+            CPUAccessFlags: 0,
+            MiscFlags: 0
+        };
+
+        let initial_data = initial_data.map_or(null(), |data| data as *const _);
+
+        let mut texture = null_mut::<ID3D11Texture2D>();
+        let hr = unsafe { device.CreateTexture2D(&desc, initial_data, &mut texture) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(texture) } };
+
+        None
+    }
+
+    fn create_shader_view(device: &ComPtr<ID3D11Device>, texture: &ComPtr<ID3D11Texture2D>) -> Option<ComPtr<ID3D11ShaderResourceView>> {
+        let mut view = null_mut::<ID3D11ShaderResourceView>();
+        let hr = unsafe { device.CreateShaderResourceView(texture.as_ptr() as *mut ID3D11Resource, null(), &mut view) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(view) } };
+
+        None
+    }
+
+    fn create_render_view(device: &ComPtr<ID3D11Device>, texture: &ComPtr<ID3D11Texture2D>) -> Option<ComPtr<ID3D11RenderTargetView>> {
+        let mut view = null_mut::<ID3D11RenderTargetView>();
+        let hr = unsafe { device.CreateRenderTargetView(texture.as_ptr() as *mut ID3D11Resource, null(), &mut view) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(view) } };
+
+        None
+    }
+}
+unsafe impl Send for DeviceResource {}
+unsafe impl Sync for DeviceResource {}
+
+#[derive(Clone, Default)]
+pub struct DeviceResources {
+    pub registry: ResourceRegistry,
+    pub resources: HashMap<ResourceId, DeviceResource>
+}
+impl DeviceResources {
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    pub fn create(&mut self, device: &ComPtr<ID3D11Device>, desc: ResourceDesc) -> Option<ResourceId> {
+        let resource = DeviceResource::create(device, &desc)?;
+        let id = self.registry.record(desc);
+        self.resources.insert(id, resource);
+
+        Some(id)
+    }
+
+    pub fn replace(&mut self, device: &ComPtr<ID3D11Device>, id: ResourceId, desc: ResourceDesc) -> bool {
+        if self.registry.get(id).is_none() { return false };
+
+        let resource = match DeviceResource::create(device, &desc) {
+            Some(resource) => resource,
+            None => return false
+        };
+        self.registry.update(id, desc);
+        self.resources.insert(id, resource);
+
+        true
+    }
+
+    pub fn get(&self, id: ResourceId) -> Option<&DeviceResource> {
+        self.resources.get(&id)
+    }
+
+    pub fn release(&mut self, id: ResourceId) {
+        self.resources.remove(&id);
+        self.registry.forget(id);
+    }
+
+    pub fn release_all(&mut self) {
+        self.resources.clear();
+    }
+
+    pub fn rebuild(&mut self, device: &ComPtr<ID3D11Device>) -> bool {
+        self.resources.clear();
+
+        let mut rebuilt = true;
+        for (id, desc) in self.registry.iter() {
+            match DeviceResource::create(device, desc) {
+                Some(resource) => { self.resources.insert(*id, resource); },
+                None => rebuilt = false
+            }
+        }
+
+        rebuilt
+    }
+}
+unsafe impl Send for DeviceResources {}
+unsafe impl Sync for DeviceResources {}
\ No newline at end of file
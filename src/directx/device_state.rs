@@ -0,0 +1,23 @@
+use crate::{ ColorOutput, SwapchainConfig, DeviceConfig, DeviceRemovedReason, DeviceResources };
+
+#[cfg(feature = "compiler")]
+use crate::ShaderWatcher;
+
+#[cfg(feature = "diagnostics")]
+use crate::MessageDispatcher;
+
+#[derive(Clone)]
+pub struct DeviceState {
+    pub reason: Option<DeviceRemovedReason>,
+    pub swapchain_config: SwapchainConfig,
+    pub device_config: DeviceConfig,
+    pub color_output: ColorOutput,
+    pub premultiplied_alpha: bool,
+    pub resources: DeviceResources,
+    #[cfg(feature = "compiler")]
+    pub watcher: Option<ShaderWatcher>,
+    #[cfg(feature = "diagnostics")]
+    pub dispatcher: Option<MessageDispatcher>
+}
+unsafe impl Send for DeviceState {}
+unsafe impl Sync for DeviceState {}
\ No newline at end of file
@@ -1,66 +1,83 @@
 use crate::dependencies::{
-    null_mut, SUCCEEDED, ID3D11Device, ID3D11Texture2D, IDXGIAdapter, IDXGISwapChain, IDXGIDevice, IDXGISurface, Interface
+    null_mut, SUCCEEDED, IDXGIAdapter1, IDXGISwapChain3, DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT, ID3D11Device, ID3D11Texture2D, IDXGIAdapter, IDXGISwapChain, IDXGIDevice, IDXGISurface
 };
 
+use crate::{ ComPtr, Adapter, AdapterInfo };
+
 #[derive(Clone)]
 pub struct DirectXGI {
-    pub swapchain: *mut IDXGISwapChain,
-    pub device: *mut IDXGIDevice,
-    pub adapter: *mut IDXGIAdapter,
-    pub surface: *mut IDXGISurface
+    pub swapchain: ComPtr<IDXGISwapChain>,
+    pub device: ComPtr<IDXGIDevice>,
+    pub adapter: ComPtr<IDXGIAdapter>,
+    pub surface: Option<ComPtr<IDXGISurface>>
 }
 impl DirectXGI {
-    pub fn get(swapchain: *mut IDXGISwapChain, device: *mut ID3D11Device, backbuffer: *mut ID3D11Texture2D) -> Option<Self> {
+    pub fn get(swapchain: *mut IDXGISwapChain, device: &ComPtr<ID3D11Device>, backbuffer: &ComPtr<ID3D11Texture2D>) -> Option<Self> {
+        let swapchain = match unsafe { ComPtr::from_raw_borrowed(swapchain) } {
+            Some(swapchain) => swapchain,
+            None => return None
+        };
         let device = match Self::get_device(device) {
             Some(device) => device,
             None => return None
         };
-        let adapter = match Self::get_adapter(device) {
+        let adapter = match Self::get_adapter(&device) {
             Some(adapter) => adapter,
             None => return None
         };
         let surface = match Self::get_surface(backbuffer) {
-            Some(surface) => surface,
+            Some(surface) => Some(surface),
             None => return None
         };
 
         Some(Self { swapchain, device, adapter, surface })
     }
 
-    fn get_device(d3d_device: *mut ID3D11Device) -> Option<*mut IDXGIDevice> {
-        let mut device = null_mut::<IDXGIDevice>();
-        let hr = unsafe { (*d3d_device).QueryInterface(
-            &IDXGIDevice::uuidof(),
-            &mut device as *mut _ as *mut *mut _
-        ) };
+    pub fn adapter_info(&self) -> Option<AdapterInfo> {
+        match self.adapter.cast::<IDXGIAdapter1>() {
+            Some(adapter) => Adapter::describe(&adapter, 0),
+            None => None
+        }
+    }
 
-        if SUCCEEDED(hr) { return Some(device) };
+    pub fn set_color_space(&self, color_space: u32) -> bool {
+        let swapchain = match self.swapchain.cast::<IDXGISwapChain3>() {
+            Some(swapchain) => swapchain,
+            None => return false
+        };
 
-        None
+        let mut support = 0;
+        let hr = unsafe { swapchain.CheckColorSpaceSupport(color_space, &mut support) };
+        if !SUCCEEDED(hr) || support & DXGI_SWAP_CHAIN_COLOR_SPACE_SUPPORT_FLAG_PRESENT == 0 { return false };
+
+        SUCCEEDED(unsafe { swapchain.SetColorSpace1(color_space) })
     }
 
-    fn get_adapter(device: *mut IDXGIDevice) -> Option<*mut IDXGIAdapter> {
-        let mut adapter = null_mut::<IDXGIAdapter>();
-        let hr = unsafe { (*device).GetAdapter(
-            &mut adapter as *mut _ as *mut *mut _
-        ) };
+    pub fn release_surface(&mut self) {
+        self.surface = None;
+    }
 
-        if SUCCEEDED(hr) { return Some(adapter) };
+    pub fn acquire_surface(&mut self, backbuffer: &ComPtr<ID3D11Texture2D>) -> bool {
+        self.surface = Self::get_surface(backbuffer);
+        self.surface.is_some()
+    }
 
-        None
+    fn get_device(d3d_device: &ComPtr<ID3D11Device>) -> Option<ComPtr<IDXGIDevice>> {
+        d3d_device.cast::<IDXGIDevice>()
     }
 
-    fn get_surface(backbuffer: *mut ID3D11Texture2D) -> Option<*mut IDXGISurface> {
-        let mut surface: *mut IDXGISurface = null_mut();
-        let hr = unsafe { (*backbuffer).QueryInterface(
-            &IDXGISurface::uuidof(),
-            &mut surface as *mut _ as *mut *mut _
-        ) };
+    fn get_adapter(device: &ComPtr<IDXGIDevice>) -> Option<ComPtr<IDXGIAdapter>> {
+        let mut adapter = null_mut::<IDXGIAdapter>();
+        let hr = unsafe { device.GetAdapter(&mut adapter) };
 
-        if SUCCEEDED(hr) { return Some(surface) };
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(adapter) } };
 
         None
     }
+
+    fn get_surface(backbuffer: &ComPtr<ID3D11Texture2D>) -> Option<ComPtr<IDXGISurface>> {
+        backbuffer.cast::<IDXGISurface>()
+    }
 }
 unsafe impl Send for DirectXGI {}
 unsafe impl Sync for DirectXGI {}
\ No newline at end of file
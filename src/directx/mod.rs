@@ -4,27 +4,85 @@ pub use d3d::Direct3D;
 mod dxgi;
 pub use dxgi::DirectXGI;
 
+mod adapter;
+pub use adapter::Adapter;
+
+mod device_resources;
+pub use device_resources::{ DeviceResource, DeviceResources };
+
+mod device_state;
+pub use device_state::DeviceState;
+
 use crate::dependencies::{
-    IDXGISwapChain
+    take, SUCCEEDED, IDXGISwapChain, DXGI_FORMAT_UNKNOWN
 };
 
-use crate::{ WindowHandle, Shader, Renderer};
+use crate::{ WindowHandle, Shader, Renderer, ColorOutput, SwapchainConfig, DeviceConfig, DeviceRemovedReason, ResourceId, ResourceDesc };
+
+#[cfg(feature = "compiler")]
+use crate::{ ShaderWatcher, ShaderReload };
+
+#[cfg(feature = "diagnostics")]
+use crate::{ InfoQueue, DebugMessage };
 
 #[derive(Clone)]
 pub struct DirectX {
     pub d3d: Direct3D,
     pub dxgi: DirectXGI,
     pub shader: Option<Shader>,
-    pub renderer: Option<Renderer>
+    pub renderer: Option<Renderer>,
+    pub color_output: ColorOutput,
+    pub premultiplied_alpha: bool,
+    pub swapchain_config: SwapchainConfig,
+    pub device_config: DeviceConfig,
+    pub resources: DeviceResources,
+    pub lost: Option<DeviceRemovedReason>,
+    #[cfg(feature = "compiler")]
+    pub watcher: Option<ShaderWatcher>,
+    #[cfg(feature = "diagnostics")]
+    pub diagnostics: Option<InfoQueue>
 }
 impl DirectX {
     pub fn create(window_handle: &WindowHandle) -> Option<Self> {
-        let (swapchain, device, context) = match Direct3D::create_device_and_swapchain(&window_handle) {
+        Self::create_with_config(window_handle, SwapchainConfig::build())
+    }
+
+    pub fn create_with_config(window_handle: &WindowHandle, config: SwapchainConfig) -> Option<Self> {
+        Self::create_with_adapter(window_handle, config, None)
+    }
+
+    pub fn create_with_adapter(window_handle: &WindowHandle, config: SwapchainConfig, adapter: Option<&Adapter>) -> Option<Self> {
+        Self::create_with_device(window_handle, config, &DeviceConfig::build(), adapter)
+    }
+
+    pub fn create_with_device(window_handle: &WindowHandle, config: SwapchainConfig, device_config: &DeviceConfig, adapter: Option<&Adapter>) -> Option<Self> {
+        if config.validate().is_err() {
+            return None;
+        }
+        let config = match config.allow_tearing {
+            true => config.tearing_support(Adapter::supports_tearing()),
+            false => config
+        };
+
+        let (swapchain, _device, _context) = match Direct3D::create_device_and_swapchain(window_handle, &config, device_config, adapter) {
             Some((swapchain, device, context)) => (swapchain, device, context),
             None => return None
         };
 
-        Self::get(swapchain)
+        let mut dx = match Self::get(swapchain.as_ptr()) {
+            Some(dx) => dx,
+            None => return None
+        };
+        let color_space = config.color_space().filter(|color_space| dx.dxgi.set_color_space(*color_space));
+        dx.color_output = ColorOutput::detect_with_color_space(config.view_format().rtv_format(), color_space);
+        dx.swapchain_config = config;
+        dx.device_config = device_config.clone();
+        #[cfg(feature = "diagnostics")]
+        if device_config.debug {
+            dx.enable_diagnostics();
+        }
+
+        Some(dx)
     }
 
     pub fn get(swapchain: *mut IDXGISwapChain) -> Option<Self> {
@@ -32,39 +90,219 @@ impl DirectX {
             Some(d3d) => d3d,
             None => return None
         };
-        let dxgi = match DirectXGI::get(swapchain, d3d.device, d3d.backbuffer) {
+        let dxgi = match d3d.backbuffer.as_ref() {
+            Some(backbuffer) => DirectXGI::get(swapchain, &d3d.device, backbuffer),
+            None => return None
+        };
+        let dxgi = match dxgi {
             Some(dxgi) => dxgi,
             None => return None
         };
         let shader = None;
         let renderer = None;
+        let color_output = ColorOutput::detect(d3d.desc.BufferDesc.Format);
 
-        Some(Self { dxgi, d3d, shader, renderer })
+        Some(Self {
+            dxgi,
+            d3d,
+            shader,
+            renderer,
+            color_output,
+            premultiplied_alpha: false,
+            swapchain_config: SwapchainConfig::build(),
+            device_config: DeviceConfig::build(),
+            resources: DeviceResources::build(),
+            lost: None,
+            #[cfg(feature = "compiler")]
+            watcher: None,
+            #[cfg(feature = "diagnostics")]
+            diagnostics: None
+        })
     }
 
     pub fn update(&mut self, swapchain: *mut IDXGISwapChain) {
-        if self.dxgi.swapchain != swapchain {
+        if self.dxgi.swapchain.as_ptr() != swapchain {
             self.release();
             let mut dx = match Self::get(swapchain) {
                 Some(dx) => dx,
                 None => return
             };
-            dx.shader = self.shader.take();
-            dx.renderer = Some(Renderer::create(&dx.d3d));
+            dx.color_output = match dx.color_output.format == self.color_output.format {
+                true => self.color_output,
+                false => dx.color_output.paper_white(self.color_output.paper_white_nits)
+            };
+            dx.premultiplied_alpha = self.premultiplied_alpha;
+            dx.swapchain_config = self.swapchain_config;
+            dx.device_config = self.device_config.clone();
+            dx.resources = take(&mut self.resources);
+            if dx.d3d.device.as_ptr() != self.d3d.device.as_ptr() {
+                dx.resources.rebuild(&dx.d3d.device);
+            } else if dx.color_output.encoding == self.color_output.encoding {
+                dx.shader = self.shader.take();
+            }
+            #[cfg(feature = "compiler")]
+            { dx.watcher = self.watcher.take(); }
+            #[cfg(feature = "diagnostics")]
+            { dx.diagnostics = self.diagnostics.take().and_then(|diagnostics| diagnostics.rebind(&dx.d3d.device)); }
+            dx.renderer = Some(dx.create_renderer());
             *self = dx;
+        } else if let Some(desc) = Direct3D::get_desc(swapchain) && [desc.BufferDesc.Width, desc.BufferDesc.Height] != self.d3d.resolution {
+            self.before_resize();
+            self.after_resize();
+        }
+    }
+
+    /// Must be called from a `ResizeBuffers` hook (`VTable::RESIZE_BUFFERS`) before the original method runs:
+    /// the resize fails while the renderer, surface or backbuffer still reference the old buffers.
+    pub fn before_resize(&mut self) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.release_backbuffer();
+        }
+        self.dxgi.release_surface();
+        self.d3d.release_backbuffer();
+    }
+
+    /// Call after the original `ResizeBuffers` returns to reacquire the backbuffer at its new size.
+    pub fn after_resize(&mut self) -> bool {
+        if !self.d3d.acquire_backbuffer(self.dxgi.swapchain.as_ptr()) {
+            return false;
+        }
+
+        let backbuffer = match self.d3d.backbuffer.as_ref() {
+            Some(backbuffer) => backbuffer,
+            None => return false
+        };
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.acquire_backbuffer(backbuffer, self.d3d.resolution);
+        }
+
+        self.dxgi.acquire_surface(backbuffer)
+    }
+
+    pub fn present(&mut self) -> bool {
+        let hr = unsafe { self.dxgi.swapchain.Present(self.swapchain_config.sync_interval, self.swapchain_config.present_flags()) };
+        if DeviceRemovedReason::is_device_lost(hr) && self.lost.is_none() {
+            self.lost = self.removed_reason().or(DeviceRemovedReason::from_hresult(hr));
+        }
+
+        SUCCEEDED(hr)
+    }
+
+    pub fn removed_reason(&self) -> Option<DeviceRemovedReason> {
+        DeviceRemovedReason::from_hresult(unsafe { self.d3d.device.GetDeviceRemovedReason() })
+    }
+
+    pub fn check_device(&mut self) -> Option<DeviceRemovedReason> {
+        if self.lost.is_none() {
+            self.lost = self.removed_reason();
+        }
+
+        self.lost
+    }
+
+    pub fn is_lost(&self) -> bool {
+        self.lost.is_some()
+    }
+
+    pub fn teardown(mut self) -> Box<DeviceState> {
+        self.release();
+        self.renderer = None;
+        self.shader = None;
+
+        let mut resources = take(&mut self.resources);
+        resources.release_all();
+
+        unsafe {
+            self.d3d.context.ClearState();
+            self.d3d.context.Flush();
+        }
+
+        Box::new(DeviceState {
+            reason: self.lost,
+            swapchain_config: self.swapchain_config,
+            device_config: self.device_config.clone(),
+            color_output: self.color_output,
+            premultiplied_alpha: self.premultiplied_alpha,
+            resources,
+            #[cfg(feature = "compiler")]
+            watcher: self.watcher.take(),
+            #[cfg(feature = "diagnostics")]
+            dispatcher: self.diagnostics.take().map(|diagnostics| diagnostics.dispatcher)
+        })
+    }
+
+    pub fn restore(state: Box<DeviceState>, window_handle: &WindowHandle, adapter: Option<&Adapter>) -> Result<Self, Box<DeviceState>> {
+        let reason = state.reason;
+        let mut dx = match Self::create_with_device(window_handle, state.swapchain_config, &state.device_config, adapter) {
+            Some(dx) => dx,
+            None => return Err(state)
+        };
+
+        dx.color_output = dx.color_output.paper_white(state.color_output.paper_white_nits);
+        dx.premultiplied_alpha = state.premultiplied_alpha;
+        let state = *state;
+        dx.resources = state.resources;
+        #[cfg(feature = "compiler")]
+        { dx.watcher = state.watcher; }
+        #[cfg(feature = "diagnostics")]
+        if let Some(dispatcher) = state.dispatcher && let Some(diagnostics) = dx.enable_diagnostics() {
+            diagnostics.dispatcher = dispatcher;
         }
+
+        if !dx.resources.rebuild(&dx.d3d.device) {
+            let mut retry = dx.teardown();
+            retry.reason = retry.reason.or(reason);
+            return Err(retry);
+        }
+
+        Ok(dx)
+    }
+
+    pub fn create_resource(&mut self, desc: ResourceDesc) -> Option<ResourceId> {
+        let id = self.resources.create(&self.d3d.device, desc);
+        if id.is_none() {
+            self.check_device();
+        }
+
+        id
+    }
+
+    pub fn get_resource(&self, id: ResourceId) -> Option<&DeviceResource> {
+        self.resources.get(id)
+    }
+
+    pub fn release_resource(&mut self, id: ResourceId) {
+        self.resources.release(id);
+    }
+
+    pub fn set_sync_interval(&mut self, sync_interval: u32) {
+        self.swapchain_config.sync_interval = sync_interval.min(SwapchainConfig::MAX_SYNC_INTERVAL);
+    }
+
+    pub fn on_resize(&mut self, width: u32, height: u32) -> bool {
+        self.before_resize();
+
+        let hr = unsafe { self.dxgi.swapchain.ResizeBuffers(0, width, height, DXGI_FORMAT_UNKNOWN, self.d3d.desc.Flags) };
+
+        self.after_resize() && SUCCEEDED(hr)
     }
 
-    pub fn setup(&mut self) {
+    pub fn setup(&mut self) -> bool {
+        #[cfg(feature = "compiler")]
+        self.reload_shader();
+
         if self.shader.is_none() {
             self.create_shader();
         }
 
-        if let Some(shader) = self.shader.as_ref() {
-            shader.setup(self.d3d.context);
-        } else {
-            panic!("Shader creation failed");
-        }
+        let shader = match self.shader.as_ref() {
+            Some(shader) => shader,
+            None => {
+                self.check_device();
+                return false;
+            }
+        };
+        shader.setup(&self.d3d.context);
 
         if self.renderer.is_none() {
             self.renderer = Some(self.create_renderer());
@@ -72,6 +310,8 @@ impl DirectX {
                 renderer.setup();
             }
         }
+
+        true
     }
 
     pub fn release(&mut self) {
@@ -81,51 +321,83 @@ impl DirectX {
         }
     }
 
-    pub fn get_renderer(&mut self) -> &mut Renderer {
-        let renderer = self.renderer.as_mut().unwrap();
+    pub fn get_renderer(&mut self) -> Option<&mut Renderer> {
+        let renderer = self.renderer.as_mut()?;
         renderer.set_own_render();
-        renderer
+
+        Some(renderer)
     }
 
-    fn create_shader(&mut self) {
-        self.shader = Some(match Shader::build(
-            br#"
-            struct VSInput {
-                float3 pos   : POSITION;
-                float4 color : COLOR;
-            };
+    #[cfg(feature = "compiler")]
+    pub fn watch_shader(&mut self, watcher: ShaderWatcher) {
+        self.watcher = Some(watcher);
+    }
 
-            struct PSInput {
-                float4 pos   : SV_POSITION;
-                float4 color : COLOR;
-            };
+    #[cfg(feature = "compiler")]
+    fn reload_shader(&mut self) {
+        let watcher = match self.watcher.as_ref() {
+            Some(watcher) => watcher,
+            None => return
+        };
 
-            PSInput VSMain(VSInput input) {
-                PSInput output;
-                output.pos = float4(input.pos, 1.0);
-                output.color = input.color;
-                return output;
+        if let Some(ShaderReload::Compiled { vs_bytecode, ps_bytecode }) = watcher.poll() {
+            match Shader::from_bytecode(&vs_bytecode, &ps_bytecode, &self.d3d.device) {
+                Some(shader) => self.shader = Some(shader),
+                None => watcher.report_error("failed to create shader from compiled bytecode")
             }
-            "#,
-            br#"
-            struct PSInput {
-                float4 pos   : SV_POSITION;
-                float4 color : COLOR;
-            };
+        }
+    }
 
-            float4 PSMain(PSInput input) : SV_TARGET {
-                return input.color;
-            }
-            "#,
-            self.d3d.device
-        ) {
-            Some(shader) => shader,
-            None => return
-        });
+    #[cfg(feature = "diagnostics")]
+    pub fn enable_diagnostics(&mut self) -> Option<&mut InfoQueue> {
+        if self.diagnostics.is_none() {
+            self.diagnostics = InfoQueue::create(&self.d3d.device);
+        }
+
+        self.diagnostics.as_mut()
+    }
+
+    #[cfg(feature = "diagnostics")]
+    pub fn poll_diagnostics(&mut self) -> Vec<DebugMessage> {
+        match self.diagnostics.as_mut() {
+            Some(diagnostics) => diagnostics.poll(),
+            None => vec![]
+        }
+    }
+
+    pub fn set_color_output(&mut self, color_output: ColorOutput) {
+        if color_output.encoding != self.color_output.encoding {
+            self.shader = None;
+        }
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_color_output(color_output);
+        }
+        self.color_output = color_output;
+    }
+
+    pub fn set_premultiplied_alpha(&mut self, enabled: bool) {
+        if let Some(renderer) = self.renderer.as_mut() {
+            renderer.set_premultiplied_alpha(enabled);
+        }
+        self.premultiplied_alpha = enabled;
+    }
+
+    fn create_shader(&mut self) {
+        #[cfg(feature = "compiler")]
+        if let Some((vs_bytecode, ps_bytecode)) = self.watcher.as_ref().and_then(|watcher| watcher.last_compiled())
+            && let Some(shader) = Shader::from_bytecode(&vs_bytecode, &ps_bytecode, &self.d3d.device) {
+            self.shader = Some(shader);
+            return;
+        }
+
+        self.shader = Shader::overlay_2d_encoded(&self.d3d.device, self.color_output.encoding);
     }
 
     fn create_renderer(&mut self) -> Renderer {
-        Renderer::create(&self.d3d)
+        let mut renderer = Renderer::create(&self.d3d);
+        renderer.color_output = self.color_output;
+        renderer.set_premultiplied_alpha(self.premultiplied_alpha);
+        renderer
     }
 }
 unsafe impl Send for DirectX {}
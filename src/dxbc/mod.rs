@@ -0,0 +1,251 @@
+mod reader;
+use reader::Reader;
+
+mod signature;
+pub use signature::{ Signature, SignatureElement, ComponentType, SystemValue };
+
+mod resource_definition;
+pub use resource_definition::{ ResourceDefinition, ConstantBufferDesc, ShaderVariable, ShaderType, ShaderTypeMember, VariableClass, VariableType, ResourceBinding, InputType };
+
+mod shader_program;
+pub use shader_program::{ ShaderProgram, ProgramType };
+
+mod statistics;
+pub use statistics::ShaderStatistics;
+
+mod reflection;
+pub use reflection::{ ShaderReflection, InputElement };
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DxbcChunk {
+    pub fourcc: [u8; 4],
+    pub data: Vec<u8>
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dxbc {
+    pub checksum: [u8; 16],
+    pub version: u32,
+    pub size: u32,
+    pub chunks: Vec<DxbcChunk>
+}
+impl Dxbc {
+    pub const MAGIC: [u8; 4] = *b"DXBC";
+    const HEADER_SIZE: usize = 32;
+
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let reader = Reader::build(data);
+
+        if reader.fourcc(0)? != Self::MAGIC { return None };
+
+        let checksum = reader.bytes(4, 16)?.try_into().ok()?;
+        let version = reader.u32(20)?;
+        let size = reader.u32(24)?;
+        let chunk_count = reader.u32(28)? as usize;
+
+        if size as usize > data.len() { return None };
+
+        let mut chunks = Vec::with_capacity(reader.capacity(Self::HEADER_SIZE, chunk_count, 4));
+        for index in 0..chunk_count {
+            let offset = reader.u32(Self::HEADER_SIZE + index * 4)? as usize;
+            let fourcc = reader.fourcc(offset)?;
+            let len = reader.u32(offset + 4)? as usize;
+            let data = reader.bytes(offset + 8, len)?.to_vec();
+
+            chunks.push(DxbcChunk { fourcc, data });
+        }
+
+        Some(Self { checksum, version, size, chunks })
+    }
+
+    pub fn chunk(&self, fourcc: &[u8; 4]) -> Option<&DxbcChunk> {
+        self.chunks.iter().find(|chunk| &chunk.fourcc == fourcc)
+    }
+
+    pub fn input_signature(&self) -> Option<Signature> {
+        [b"ISGN", b"ISG1"]
+            .into_iter()
+            .find_map(|fourcc| Signature::parse(self.chunk(fourcc)?))
+    }
+
+    pub fn output_signature(&self) -> Option<Signature> {
+        [b"OSGN", b"OSG5", b"OSG1"]
+            .into_iter()
+            .find_map(|fourcc| Signature::parse(self.chunk(fourcc)?))
+    }
+
+    pub fn resource_definition(&self) -> Option<ResourceDefinition> {
+        ResourceDefinition::parse(&self.chunk(b"RDEF")?.data)
+    }
+
+    pub fn shader_program(&self) -> Option<ShaderProgram> {
+        [b"SHEX", b"SHDR"]
+            .into_iter()
+            .find_map(|fourcc| ShaderProgram::parse(&self.chunk(fourcc)?.data))
+    }
+
+    pub fn statistics(&self) -> Option<ShaderStatistics> {
+        ShaderStatistics::parse(&self.chunk(b"STAT")?.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OVERLAY_VS: &[u8] = include_bytes!("../shader/bytecode/overlay_vs.cso");
+    const OVERLAY_PS: &[u8] = include_bytes!("../shader/bytecode/overlay_ps.cso");
+    const OVERLAY_2D_VS: &[u8] = include_bytes!("../shader/bytecode/overlay_2d_vs.cso");
+    const OVERLAY_SCRGB_PS: &[u8] = include_bytes!("../shader/bytecode/overlay_scrgb_ps.cso");
+    const OVERLAY_HDR10_PS: &[u8] = include_bytes!("../shader/bytecode/overlay_hdr10_ps.cso");
+
+    fn semantics(signature: &Signature) -> Vec<(&str, u32)> {
+        signature.elements.iter().map(|element| (element.semantic_name.as_str(), element.component_count())).collect()
+    }
+
+    #[test]
+    fn parses_container_chunks() {
+        let dxbc = Dxbc::parse(OVERLAY_VS).unwrap();
+
+        assert_eq!(dxbc.size as usize, OVERLAY_VS.len());
+        for fourcc in [b"RDEF", b"ISGN", b"OSGN", b"SHDR"] {
+            assert!(dxbc.chunk(fourcc).is_some());
+        }
+    }
+
+    #[test]
+    fn reflects_vertex_signatures() {
+        let reflection = ShaderReflection::parse(OVERLAY_VS).unwrap();
+        assert_eq!(semantics(&reflection.inputs), vec![("POSITION", 3), ("COLOR", 4)]);
+        assert_eq!(reflection.outputs.elements[0].system_value, SystemValue::Position);
+
+        let reflection = ShaderReflection::parse(OVERLAY_2D_VS).unwrap();
+        let elements = reflection.input_elements();
+        assert_eq!(elements.len(), 2);
+        assert_eq!((elements[0].semantic_name.as_str(), elements[0].component_count, elements[0].offset), ("POSITION", 2, 0));
+        assert_eq!((elements[1].semantic_name.as_str(), elements[1].component_count, elements[1].offset), ("COLOR", 4, 8));
+        assert_eq!(elements[0].component_type, ComponentType::Float32);
+    }
+
+    #[test]
+    fn reflects_programs() {
+        let program = ShaderReflection::parse(OVERLAY_2D_VS).unwrap().program.unwrap();
+        assert_eq!((program.program_type, program.major, program.minor), (ProgramType::Vertex, 4, 0));
+        assert_eq!(program.profile(), "vs_4_0");
+
+        let program = ShaderReflection::parse(OVERLAY_PS).unwrap().program.unwrap();
+        assert_eq!(program.program_type, ProgramType::Pixel);
+        assert_eq!(program.profile(), "ps_4_0");
+    }
+
+    #[test]
+    fn reflects_constant_buffers() {
+        let reflection = ShaderReflection::parse(OVERLAY_2D_VS).unwrap();
+        let projection = reflection.resources.as_ref().unwrap().constant_buffer("Projection").unwrap();
+        let variables: Vec<(&str, u32, u32)> = projection.variables.iter().map(|variable| (variable.name.as_str(), variable.offset, variable.size)).collect();
+        assert_eq!(projection.size, 16);
+        assert_eq!(variables, vec![("scale", 0, 8), ("offset", 8, 8)]);
+
+        for bytecode in [OVERLAY_SCRGB_PS, OVERLAY_HDR10_PS] {
+            let reflection = ShaderReflection::parse(bytecode).unwrap();
+            let color_output = reflection.resources.as_ref().unwrap().constant_buffer("ColorOutput").unwrap();
+            assert_eq!(color_output.variables[0].name, "color_scale");
+            assert_eq!(semantics(&reflection.outputs), vec![("SV_TARGET", 4)]);
+        }
+    }
+
+    #[test]
+    fn rejects_truncated_blobs() {
+        assert_eq!(Dxbc::parse(&[]), None);
+        assert_eq!(Dxbc::parse(b"DXBC"), None);
+        assert_eq!(Dxbc::parse(&OVERLAY_VS[..OVERLAY_VS.len() / 2]), None);
+        assert_eq!(ShaderReflection::parse(b"NOPE0000000000000000000000000000"), None);
+    }
+
+    #[test]
+    fn huge_counts_do_not_allocate() {
+        let mut header = vec![0u8; 32];
+        header[..4].copy_from_slice(b"DXBC");
+        header[20..24].copy_from_slice(&1u32.to_le_bytes());
+        header[24..28].copy_from_slice(&32u32.to_le_bytes());
+        header[28..32].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(Dxbc::parse(&header), None);
+
+        let mut program = 0x00010040u32.to_le_bytes().to_vec();
+        program.extend_from_slice(&0x4000_0000u32.to_le_bytes());
+        assert_eq!(ShaderProgram::parse(&program), None);
+
+        let mut signature = u32::MAX.to_le_bytes().to_vec();
+        signature.extend_from_slice(&8u32.to_le_bytes());
+        assert_eq!(Signature::parse(&DxbcChunk { fourcc: *b"ISGN", data: signature }), None);
+    }
+
+    #[test]
+    fn cyclic_types_are_rejected() {
+        let mut words: [u32; 26] = [
+            1, 28, 0, 0, 0xffff_0400, 0, 104,
+            104, 1, 52, 16, 0, 0,
+            104, 0, 4, 0, 76, 0,
+            0x0000_0005, 0x0001_0001, 0x0001_0000, 92,
+            104, 76, 0
+        ];
+        let parse = |words: &[u32]| {
+            let mut data: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+            data.extend_from_slice(b"x\0");
+            ResourceDefinition::parse(&data)
+        };
+
+        assert_eq!(parse(&words), None);
+
+        words[21] = 0;
+        assert!(parse(&words).is_some());
+    }
+
+    #[test]
+    fn reflects_sm5_named_types() {
+        let names = ["Scene", "world", "light", "float4x4", "Light", "direction", "intensity", "color", "float3", "float", "float4", "edx"];
+        let mut strings = vec![];
+        let mut offsets = vec![];
+        for name in names {
+            offsets.push(412 + strings.len() as u32);
+            strings.extend_from_slice(name.as_bytes());
+            strings.push(0);
+        }
+        let [scene, world, light, float4x4, light_type, direction, intensity, color, float3, float, float4, creator] = offsets[..] else { unreachable!() };
+        let unbound = u32::MAX;
+
+        let words: Vec<u32> = [
+            &[1, 60, 1, 84, 0xfffe_0500, 0x100, creator, 0x3131_4452, 60, 24, 32, 40, 36, 12][..],
+            &[0],
+            &[scene, 2, 116, 96, 0, 0],
+            &[scene, 0, 0, 0, 0, 1, 1, 0],
+            &[world, 0, 64, 2, 196, 0, unbound, 0, unbound, 0],
+            &[light, 64, 32, 2, 232, 0, unbound, 0, unbound, 0],
+            &[0x0003_0003, 0x0004_0004, 0, 0, 0, 0, 0, 0, float4x4],
+            &[0x0000_0005, 0x0008_0001, 0x0003_0000, 268, 0, 0, 0, 0, light_type],
+            &[direction, 304, 0, intensity, 340, 12, color, 376, 16],
+            &[0x0003_0001, 0x0003_0001, 0, 0, 0, 0, 0, 0, float3],
+            &[0x0003_0000, 0x0001_0001, 0, 0, 0, 0, 0, 0, float],
+            &[0x0003_0001, 0x0004_0001, 0, 0, 0, 0, 0, 0, float4]
+        ].concat();
+        let mut data: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+        assert_eq!(data.len(), 412);
+        data.extend_from_slice(&strings);
+
+        let resources = ResourceDefinition::parse(&data).unwrap();
+        assert_eq!((resources.program_type, resources.major, resources.minor, resources.creator.as_str()), (ProgramType::Vertex, 5, 0, "edx"));
+        assert_eq!(resources.binding("Scene").map(|binding| (binding.input_type, binding.bind_point)), Some((InputType::ConstantBuffer, 1)));
+
+        let scene = resources.constant_buffer("Scene").unwrap();
+        let variables: Vec<(&str, u32, u32)> = scene.variables.iter().map(|variable| (variable.name.as_str(), variable.offset, variable.size)).collect();
+        assert_eq!(variables, vec![("world", 0, 64), ("light", 64, 32)]);
+
+        let world = &scene.variables[0].ty;
+        assert_eq!((world.name.as_deref(), world.class, world.ty, world.rows, world.columns), (Some("float4x4"), VariableClass::MatrixColumns, VariableType::Float, 4, 4));
+
+        let light = &scene.variables[1].ty;
+        assert_eq!((light.name.as_deref(), light.class), (Some("Light"), VariableClass::Struct));
+        let members: Vec<(&str, Option<&str>, u16, u32)> = light.members.iter().map(|member| (member.name.as_str(), member.ty.name.as_deref(), member.ty.columns, member.offset)).collect();
+        assert_eq!(members, vec![("direction", Some("float3"), 3, 0), ("intensity", Some("float"), 1, 12), ("color", Some("float4"), 4, 16)]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+#[derive(Clone, Copy)]
+pub struct Reader<'a> {
+    pub data: &'a [u8]
+}
+impl<'a> Reader<'a> {
+    pub fn build(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    pub fn bytes(&self, offset: usize, len: usize) -> Option<&'a [u8]> {
+        self.data.get(offset..offset.checked_add(len)?)
+    }
+
+    pub fn capacity(&self, offset: usize, count: usize, element_size: usize) -> usize {
+        count.min(self.data.len().saturating_sub(offset) / element_size.max(1))
+    }
+
+    pub fn u16(&self, offset: usize) -> Option<u16> {
+        Some(u16::from_le_bytes(self.bytes(offset, 2)?.try_into().ok()?))
+    }
+
+    pub fn u32(&self, offset: usize) -> Option<u32> {
+        Some(u32::from_le_bytes(self.bytes(offset, 4)?.try_into().ok()?))
+    }
+
+    pub fn fourcc(&self, offset: usize) -> Option<[u8; 4]> {
+        self.bytes(offset, 4)?.try_into().ok()
+    }
+
+    pub fn string(&self, offset: usize) -> Option<String> {
+        let bytes = self.data.get(offset..)?;
+        let len = bytes.iter().position(|byte| *byte == 0)?;
+
+        String::from_utf8(bytes[..len].to_vec()).ok()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,124 @@
+#[cfg(windows)]
+use crate::dependencies::{
+    CString, DXGI_FORMAT, D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_VERTEX_DATA, DXGI_FORMAT_R32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT, DXGI_FORMAT_R32_UINT, DXGI_FORMAT_R32G32_UINT, DXGI_FORMAT_R32G32B32_UINT, DXGI_FORMAT_R32G32B32A32_UINT, DXGI_FORMAT_R32_SINT, DXGI_FORMAT_R32G32_SINT, DXGI_FORMAT_R32G32B32_SINT, DXGI_FORMAT_R32G32B32A32_SINT
+};
+
+use crate::dxbc::{ Dxbc, Signature, SignatureElement, ComponentType, SystemValue, ResourceDefinition, ConstantBufferDesc, ResourceBinding, ShaderProgram, ShaderStatistics };
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InputElement {
+    pub semantic_name: String,
+    pub semantic_index: u32,
+    pub component_type: ComponentType,
+    pub component_count: u32,
+    pub input_slot: u32,
+    pub offset: u32
+}
+impl InputElement {
+    pub fn size(&self) -> u32 {
+        self.component_count * 4
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderReflection {
+    pub inputs: Signature,
+    pub outputs: Signature,
+    pub resources: Option<ResourceDefinition>,
+    pub program: Option<ShaderProgram>,
+    pub statistics: Option<ShaderStatistics>
+}
+impl ShaderReflection {
+    pub fn parse(bytecode: &[u8]) -> Option<Self> {
+        let dxbc = Dxbc::parse(bytecode)?;
+
+        Some(Self {
+            inputs: dxbc.input_signature().unwrap_or_default(),
+            outputs: dxbc.output_signature().unwrap_or_default(),
+            resources: dxbc.resource_definition(),
+            program: dxbc.shader_program(),
+            statistics: dxbc.statistics()
+        })
+    }
+
+    pub fn constant_buffers(&self) -> &[ConstantBufferDesc] {
+        match self.resources.as_ref() {
+            Some(resources) => &resources.constant_buffers,
+            None => &[]
+        }
+    }
+
+    pub fn bindings(&self) -> &[ResourceBinding] {
+        match self.resources.as_ref() {
+            Some(resources) => &resources.bindings,
+            None => &[]
+        }
+    }
+
+    pub fn input_elements(&self) -> Vec<InputElement> {
+        let mut offset = 0;
+
+        self.inputs.elements
+            .iter()
+            .filter(|element| Self::is_vertex_input(element))
+            .map(|element| {
+                let input_element = InputElement {
+                    semantic_name: element.semantic_name.clone(),
+                    semantic_index: element.semantic_index,
+                    component_type: element.component_type,
+                    component_count: element.component_count(),
+                    input_slot: 0,
+                    offset
+                };
+                offset += input_element.size();
+
+                input_element
+            })
+            .collect()
+    }
+
+    fn is_vertex_input(element: &SignatureElement) -> bool {
+        !matches!(element.system_value, SystemValue::VertexId | SystemValue::InstanceId)
+    }
+}
+
+#[cfg(windows)]
+impl InputElement {
+    pub fn format(&self) -> Option<DXGI_FORMAT> {
+        let formats = match self.component_type {
+            ComponentType::Float32 => [DXGI_FORMAT_R32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT],
+            ComponentType::Uint32 => [DXGI_FORMAT_R32_UINT, DXGI_FORMAT_R32G32_UINT, DXGI_FORMAT_R32G32B32_UINT, DXGI_FORMAT_R32G32B32A32_UINT],
+            ComponentType::Sint32 => [DXGI_FORMAT_R32_SINT, DXGI_FORMAT_R32G32_SINT, DXGI_FORMAT_R32G32B32_SINT, DXGI_FORMAT_R32G32B32A32_SINT],
+            ComponentType::Unknown => return None
+        };
+
+        formats.get(self.component_count.checked_sub(1)? as usize).copied()
+    }
+}
+
+#[cfg(windows)]
+impl ShaderReflection {
+    pub fn input_layout(&self) -> Option<(Vec<CString>, Vec<D3D11_INPUT_ELEMENT_DESC>)> {
+        let elements = self.input_elements();
+
+        let mut names = Vec::with_capacity(elements.len());
+        for element in elements.iter() {
+            names.push(CString::new(element.semantic_name.as_str()).ok()?);
+        }
+
+        let mut descs = Vec::with_capacity(elements.len());
+        for (element, name) in elements.iter().zip(names.iter()) {
+            descs.push(D3D11_INPUT_ELEMENT_DESC {
+                SemanticName: name.as_ptr(),
+                SemanticIndex: element.semantic_index,
+                Format: element.format()?,
+                InputSlot: element.input_slot,
+                AlignedByteOffset: element.offset,
+                InputSlotClass: D3D11_INPUT_PER_VERTEX_DATA,
+                InstanceDataStepRate: 0
+            });
+        }
+
+        Some((names, descs))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,270 @@
+use crate::dxbc::{ Reader, ProgramType };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VariableClass {
+    Scalar,
+    Vector,
+    MatrixRows,
+    MatrixColumns,
+    Object,
+    Struct,
+    Other(u16)
+}
+impl VariableClass {
+    pub fn parse(value: u16) -> Self {
+        match value {
+            0 => Self::Scalar,
+            1 => Self::Vector,
+            2 => Self::MatrixRows,
+            3 => Self::MatrixColumns,
+            4 => Self::Object,
+            5 => Self::Struct,
+            value => Self::Other(value)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VariableType {
+    Void,
+    Bool,
+    Int,
+    Float,
+    Uint,
+    Double,
+    Other(u16)
+}
+impl VariableType {
+    pub fn parse(value: u16) -> Self {
+        match value {
+            0 => Self::Void,
+            1 => Self::Bool,
+            2 => Self::Int,
+            3 => Self::Float,
+            19 => Self::Uint,
+            39 => Self::Double,
+            value => Self::Other(value)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InputType {
+    ConstantBuffer,
+    TextureBuffer,
+    Texture,
+    Sampler,
+    UavTyped,
+    Structured,
+    UavStructured,
+    ByteAddress,
+    UavByteAddress,
+    UavAppendStructured,
+    UavConsumeStructured,
+    UavStructuredWithCounter,
+    Other(u32)
+}
+impl InputType {
+    pub fn parse(value: u32) -> Self {
+        match value {
+            0 => Self::ConstantBuffer,
+            1 => Self::TextureBuffer,
+            2 => Self::Texture,
+            3 => Self::Sampler,
+            4 => Self::UavTyped,
+            5 => Self::Structured,
+            6 => Self::UavStructured,
+            7 => Self::ByteAddress,
+            8 => Self::UavByteAddress,
+            9 => Self::UavAppendStructured,
+            10 => Self::UavConsumeStructured,
+            11 => Self::UavStructuredWithCounter,
+            value => Self::Other(value)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderTypeMember {
+    pub name: String,
+    pub offset: u32,
+    pub ty: ShaderType
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderType {
+    pub name: Option<String>,
+    pub class: VariableClass,
+    pub ty: VariableType,
+    pub rows: u16,
+    pub columns: u16,
+    pub elements: u16,
+    pub members: Vec<ShaderTypeMember>
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderVariable {
+    pub name: String,
+    pub offset: u32,
+    pub size: u32,
+    pub flags: u32,
+    pub ty: ShaderType,
+    pub default_value: Option<Vec<u8>>
+}
+impl ShaderVariable {
+    pub const USED: u32 = 0x2;
+
+    pub fn is_used(&self) -> bool {
+        self.flags & Self::USED != 0
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ConstantBufferDesc {
+    pub name: String,
+    pub size: u32,
+    pub flags: u32,
+    pub buffer_type: u32,
+    pub variables: Vec<ShaderVariable>
+}
+impl ConstantBufferDesc {
+    pub fn variable(&self, name: &str) -> Option<&ShaderVariable> {
+        self.variables.iter().find(|variable| variable.name == name)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ResourceBinding {
+    pub name: String,
+    pub input_type: InputType,
+    pub return_type: u32,
+    pub dimension: u32,
+    pub sample_count: u32,
+    pub bind_point: u32,
+    pub bind_count: u32,
+    pub flags: u32
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ResourceDefinition {
+    pub program_type: ProgramType,
+    pub major: u8,
+    pub minor: u8,
+    pub flags: u32,
+    pub creator: String,
+    pub constant_buffers: Vec<ConstantBufferDesc>,
+    pub bindings: Vec<ResourceBinding>
+}
+impl ResourceDefinition {
+    const CONSTANT_BUFFER_SIZE: usize = 24;
+    const BINDING_SIZE: usize = 32;
+    const MEMBER_SIZE: usize = 12;
+    const MAX_TYPE_DEPTH: usize = 32;
+
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let reader = Reader::build(data);
+        let constant_buffer_count = reader.u32(0)? as usize;
+        let constant_buffer_offset = reader.u32(4)? as usize;
+        let binding_count = reader.u32(8)? as usize;
+        let binding_offset = reader.u32(12)? as usize;
+        let minor = reader.bytes(16, 1)?[0];
+        let major = reader.bytes(17, 1)?[0];
+        let program_type = ProgramType::parse(reader.u16(18)? as u32);
+        let flags = reader.u32(20)?;
+        let creator = reader.string(reader.u32(24)? as usize)?;
+        let sm5 = major >= 5;
+
+        let mut constant_buffers = Vec::with_capacity(reader.capacity(constant_buffer_offset, constant_buffer_count, Self::CONSTANT_BUFFER_SIZE));
+        for index in 0..constant_buffer_count {
+            let offset = constant_buffer_offset + index * Self::CONSTANT_BUFFER_SIZE;
+            constant_buffers.push(Self::parse_constant_buffer(reader, offset, sm5)?);
+        }
+
+        let mut bindings = Vec::with_capacity(reader.capacity(binding_offset, binding_count, Self::BINDING_SIZE));
+        for index in 0..binding_count {
+            let offset = binding_offset + index * Self::BINDING_SIZE;
+            bindings.push(ResourceBinding {
+                name: reader.string(reader.u32(offset)? as usize)?,
+                input_type: InputType::parse(reader.u32(offset + 4)?),
+                return_type: reader.u32(offset + 8)?,
+                dimension: reader.u32(offset + 12)?,
+                sample_count: reader.u32(offset + 16)?,
+                bind_point: reader.u32(offset + 20)?,
+                bind_count: reader.u32(offset + 24)?,
+                flags: reader.u32(offset + 28)?
+            });
+        }
+
+        Some(Self { program_type, major, minor, flags, creator, constant_buffers, bindings })
+    }
+
+    pub fn constant_buffer(&self, name: &str) -> Option<&ConstantBufferDesc> {
+        self.constant_buffers.iter().find(|buffer| buffer.name == name)
+    }
+
+    pub fn binding(&self, name: &str) -> Option<&ResourceBinding> {
+        self.bindings.iter().find(|binding| binding.name == name)
+    }
+
+    fn parse_constant_buffer(reader: Reader, offset: usize, sm5: bool) -> Option<ConstantBufferDesc> {
+        let name = reader.string(reader.u32(offset)? as usize)?;
+        let variable_count = reader.u32(offset + 4)? as usize;
+        let variable_offset = reader.u32(offset + 8)? as usize;
+        let size = reader.u32(offset + 12)?;
+        let flags = reader.u32(offset + 16)?;
+        let buffer_type = reader.u32(offset + 20)?;
+        let variable_size = if sm5 { 40 } else { 24 };
+
+        let mut variables = Vec::with_capacity(reader.capacity(variable_offset, variable_count, variable_size));
+        for index in 0..variable_count {
+            let offset = variable_offset + index * variable_size;
+            let default_value_offset = reader.u32(offset + 20)? as usize;
+            let size = reader.u32(offset + 8)?;
+
+            variables.push(ShaderVariable {
+                name: reader.string(reader.u32(offset)? as usize)?,
+                offset: reader.u32(offset + 4)?,
+                size,
+                flags: reader.u32(offset + 12)?,
+                ty: Self::parse_type(reader, reader.u32(offset + 16)? as usize, sm5, 0)?,
+                default_value: match default_value_offset {
+                    0 => None,
+                    offset => Some(reader.bytes(offset, size as usize)?.to_vec())
+                }
+            });
+        }
+
+        Some(ConstantBufferDesc { name, size, flags, buffer_type, variables })
+    }
+
+    fn parse_type(reader: Reader, offset: usize, sm5: bool, depth: usize) -> Option<ShaderType> {
+        if depth > Self::MAX_TYPE_DEPTH { return None };
+
+        let class = VariableClass::parse(reader.u16(offset)?);
+        let ty = VariableType::parse(reader.u16(offset + 2)?);
+        let rows = reader.u16(offset + 4)?;
+        let columns = reader.u16(offset + 6)?;
+        let elements = reader.u16(offset + 8)?;
+        let member_count = reader.u16(offset + 10)? as usize;
+        let member_offset = reader.u32(offset + 12)? as usize;
+        let name = match sm5 {
+            true => match reader.u32(offset + 32)? {
+                0 => None,
+                name_offset => reader.string(name_offset as usize)
+            },
+            false => None
+        };
+
+        let mut members = Vec::with_capacity(reader.capacity(member_offset, member_count, Self::MEMBER_SIZE));
+        for index in 0..member_count {
+            let offset = member_offset + index * Self::MEMBER_SIZE;
+            members.push(ShaderTypeMember {
+                name: reader.string(reader.u32(offset)? as usize)?,
+                ty: Self::parse_type(reader, reader.u32(offset + 4)? as usize, sm5, depth + 1)?,
+                offset: reader.u32(offset + 8)?
+            });
+        }
+
+        Some(ShaderType { name, class, ty, rows, columns, elements, members })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use crate::dxbc::{ Reader };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProgramType {
+    Pixel,
+    Vertex,
+    Geometry,
+    Hull,
+    Domain,
+    Compute,
+    Other(u32)
+}
+impl ProgramType {
+    pub fn parse(value: u32) -> Self {
+        match value {
+            0 | 0xffff => Self::Pixel,
+            1 | 0xfffe => Self::Vertex,
+            2 | 0x4753 => Self::Geometry,
+            3 | 0x4853 => Self::Hull,
+            4 | 0x4453 => Self::Domain,
+            5 | 0x4353 => Self::Compute,
+            value => Self::Other(value)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderProgram {
+    pub program_type: ProgramType,
+    pub major: u8,
+    pub minor: u8,
+    pub tokens: Vec<u32>
+}
+impl ShaderProgram {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let reader = Reader::build(data);
+        let version = reader.u32(0)?;
+        let length = reader.u32(4)? as usize;
+
+        let mut tokens = Vec::with_capacity(reader.capacity(0, length, 4));
+        for index in 0..length {
+            tokens.push(reader.u32(index * 4)?);
+        }
+
+        Some(Self {
+            program_type: ProgramType::parse(version >> 16),
+            major: ((version >> 4) & 0xf) as u8,
+            minor: (version & 0xf) as u8,
+            tokens
+        })
+    }
+
+    pub fn profile(&self) -> String {
+        let prefix = match self.program_type {
+            ProgramType::Pixel => "ps",
+            ProgramType::Vertex => "vs",
+            ProgramType::Geometry => "gs",
+            ProgramType::Hull => "hs",
+            ProgramType::Domain => "ds",
+            ProgramType::Compute => "cs",
+            ProgramType::Other(_) => "unknown"
+        };
+
+        format!("{}_{}_{}", prefix, self.major, self.minor)
+    }
+}
\ No newline at end of file
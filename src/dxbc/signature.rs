@@ -0,0 +1,126 @@
+use crate::dxbc::{ Reader, DxbcChunk };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComponentType {
+    Unknown,
+    Uint32,
+    Sint32,
+    Float32
+}
+impl ComponentType {
+    pub fn parse(value: u32) -> Self {
+        match value {
+            1 => Self::Uint32,
+            2 => Self::Sint32,
+            3 => Self::Float32,
+            _ => Self::Unknown
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SystemValue {
+    Undefined,
+    Position,
+    ClipDistance,
+    CullDistance,
+    RenderTargetArrayIndex,
+    ViewportArrayIndex,
+    VertexId,
+    PrimitiveId,
+    InstanceId,
+    IsFrontFace,
+    SampleIndex,
+    Target,
+    Depth,
+    Coverage,
+    Other(u32)
+}
+impl SystemValue {
+    pub fn parse(value: u32) -> Self {
+        match value {
+            0 => Self::Undefined,
+            1 => Self::Position,
+            2 => Self::ClipDistance,
+            3 => Self::CullDistance,
+            4 => Self::RenderTargetArrayIndex,
+            5 => Self::ViewportArrayIndex,
+            6 => Self::VertexId,
+            7 => Self::PrimitiveId,
+            8 => Self::InstanceId,
+            9 => Self::IsFrontFace,
+            10 => Self::SampleIndex,
+            64 => Self::Target,
+            65 => Self::Depth,
+            66 => Self::Coverage,
+            value => Self::Other(value)
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SignatureElement {
+    pub stream: u32,
+    pub semantic_name: String,
+    pub semantic_index: u32,
+    pub system_value: SystemValue,
+    pub component_type: ComponentType,
+    pub register: u32,
+    pub mask: u8,
+    pub read_write_mask: u8,
+    pub min_precision: u32
+}
+impl SignatureElement {
+    pub fn component_count(&self) -> u32 {
+        32 - (self.mask as u32).leading_zeros()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Signature {
+    pub elements: Vec<SignatureElement>
+}
+impl Signature {
+    pub fn parse(chunk: &DxbcChunk) -> Option<Self> {
+        let (element_size, has_stream, has_min_precision) = match &chunk.fourcc {
+            b"ISGN" | b"OSGN" => (24, false, false),
+            b"OSG5" => (28, true, false),
+            b"ISG1" | b"OSG1" => (32, true, true),
+            _ => return None
+        };
+
+        let reader = Reader::build(&chunk.data);
+        let count = reader.u32(0)? as usize;
+        let first = reader.u32(4)? as usize;
+
+        let mut elements = Vec::with_capacity(reader.capacity(first, count, element_size));
+        for index in 0..count {
+            let base = first + index * element_size;
+            let stream = if has_stream { reader.u32(base)? } else { 0 };
+            let offset = if has_stream { base + 4 } else { base };
+
+            let semantic_name = reader.string(reader.u32(offset)? as usize)?;
+            let semantic_index = reader.u32(offset + 4)?;
+            let system_value = SystemValue::parse(reader.u32(offset + 8)?);
+            let component_type = ComponentType::parse(reader.u32(offset + 12)?);
+            let register = reader.u32(offset + 16)?;
+            let mask = reader.bytes(offset + 20, 1)?[0];
+            let read_write_mask = reader.bytes(offset + 21, 1)?[0];
+            let min_precision = if has_min_precision { reader.u32(offset + 24)? } else { 0 };
+
+            elements.push(SignatureElement {
+                stream,
+                semantic_name,
+                semantic_index,
+                system_value,
+                component_type,
+                register,
+                mask,
+                read_write_mask,
+                min_precision
+            });
+        }
+
+        Some(Self { elements })
+    }
+}
\ No newline at end of file
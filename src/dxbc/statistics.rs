@@ -0,0 +1,58 @@
+use crate::dxbc::{ Reader };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ShaderStatistics {
+    pub instruction_count: u32,
+    pub temp_register_count: u32,
+    pub def_count: u32,
+    pub dcl_count: u32,
+    pub float_instruction_count: u32,
+    pub int_instruction_count: u32,
+    pub uint_instruction_count: u32,
+    pub static_flow_control_count: u32,
+    pub dynamic_flow_control_count: u32,
+    pub macro_instruction_count: u32,
+    pub temp_array_count: u32,
+    pub array_instruction_count: u32,
+    pub cut_instruction_count: u32,
+    pub emit_instruction_count: u32,
+    pub texture_normal_instructions: u32,
+    pub texture_load_instructions: u32,
+    pub texture_comp_instructions: u32,
+    pub texture_bias_instructions: u32,
+    pub texture_gradient_instructions: u32,
+    pub mov_instruction_count: u32,
+    pub movc_instruction_count: u32,
+    pub conversion_instruction_count: u32
+}
+impl ShaderStatistics {
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        let reader = Reader::build(data);
+        let field = |index: usize| reader.u32(index * 4);
+
+        Some(Self {
+            instruction_count: field(0)?,
+            temp_register_count: field(1)?,
+            def_count: field(2)?,
+            dcl_count: field(3)?,
+            float_instruction_count: field(4)?,
+            int_instruction_count: field(5)?,
+            uint_instruction_count: field(6)?,
+            static_flow_control_count: field(7)?,
+            dynamic_flow_control_count: field(8)?,
+            macro_instruction_count: field(9)?,
+            temp_array_count: field(10)?,
+            array_instruction_count: field(11)?,
+            cut_instruction_count: field(12)?,
+            emit_instruction_count: field(13)?,
+            texture_normal_instructions: field(14)?,
+            texture_load_instructions: field(15)?,
+            texture_comp_instructions: field(16)?,
+            texture_bias_instructions: field(17)?,
+            texture_gradient_instructions: field(18)?,
+            mov_instruction_count: field(19)?,
+            movc_instruction_count: field(20)?,
+            conversion_instruction_count: field(21)?
+        })
+    }
+}
\ No newline at end of file
@@ -0,0 +1,232 @@
+use crate::DeviceRemovedReason;
+
+const WM_DESTROY: u32 = 0x0002;
+const WM_MOVE: u32 = 0x0003;
+const WM_SIZE: u32 = 0x0005;
+const WM_SETFOCUS: u32 = 0x0007;
+const WM_KILLFOCUS: u32 = 0x0008;
+const WM_CLOSE: u32 = 0x0010;
+const WM_KEYDOWN: u32 = 0x0100;
+const WM_KEYUP: u32 = 0x0101;
+const WM_CHAR: u32 = 0x0102;
+const WM_SYSKEYDOWN: u32 = 0x0104;
+const WM_SYSKEYUP: u32 = 0x0105;
+const WM_MOUSEMOVE: u32 = 0x0200;
+const WM_LBUTTONDOWN: u32 = 0x0201;
+const WM_LBUTTONUP: u32 = 0x0202;
+const WM_RBUTTONDOWN: u32 = 0x0204;
+const WM_RBUTTONUP: u32 = 0x0205;
+const WM_MBUTTONDOWN: u32 = 0x0207;
+const WM_MBUTTONUP: u32 = 0x0208;
+const WM_MOUSEWHEEL: u32 = 0x020A;
+const WM_XBUTTONDOWN: u32 = 0x020B;
+const WM_XBUTTONUP: u32 = 0x020C;
+const WM_MOUSEHWHEEL: u32 = 0x020E;
+const WM_DPICHANGED: u32 = 0x02E0;
+
+const WHEEL_DELTA: f32 = 120.0;
+const DEFAULT_DPI: f32 = 96.0;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ButtonState {
+    Pressed,
+    Released
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+    X1,
+    X2
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ControlFlow {
+    Continue,
+    Exit
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Event {
+    Resized { width: u32, height: u32 },
+    Moved { x: i32, y: i32 },
+    CloseRequested,
+    Destroyed,
+    Key { key_code: u32, scan_code: u32, extended: bool, state: ButtonState, repeat: bool, system: bool },
+    Char(char),
+    MouseMoved { x: i32, y: i32 },
+    MouseButton { button: MouseButton, state: ButtonState, x: i32, y: i32 },
+    MouseWheel { delta: f32, horizontal: bool },
+    Focus(bool),
+    DpiChanged { dpi: u32, scale: f32 },
+    DeviceLost(DeviceRemovedReason),
+    DeviceRestored
+}
+impl Event {
+    pub fn translate(msg: u32, wparam: usize, lparam: isize, high_surrogate: &mut Option<u16>) -> Option<Self> {
+        let event = match msg {
+            WM_SIZE => Self::Resized { width: low_word(lparam as usize) as u32, height: high_word(lparam as usize) as u32 },
+            WM_MOVE => Self::Moved { x: signed_low_word(lparam), y: signed_high_word(lparam) },
+            WM_CLOSE => Self::CloseRequested,
+            WM_DESTROY => Self::Destroyed,
+            WM_SETFOCUS => Self::Focus(true),
+            WM_KILLFOCUS => Self::Focus(false),
+            WM_KEYDOWN | WM_KEYUP | WM_SYSKEYDOWN | WM_SYSKEYUP => {
+                let pressed = msg == WM_KEYDOWN || msg == WM_SYSKEYDOWN;
+
+                Self::Key {
+                    key_code: wparam as u32,
+                    scan_code: ((lparam >> 16) & 0xff) as u32,
+                    extended: (lparam >> 24) & 1 == 1,
+                    state: if pressed { ButtonState::Pressed } else { ButtonState::Released },
+                    repeat: pressed && (lparam >> 30) & 1 == 1,
+                    system: msg == WM_SYSKEYDOWN || msg == WM_SYSKEYUP
+                }
+            },
+            WM_CHAR => match wparam as u16 {
+                unit @ 0xd800..=0xdbff => {
+                    *high_surrogate = Some(unit);
+                    return None;
+                },
+                unit @ 0xdc00..=0xdfff => match high_surrogate.take() {
+                    Some(high) => match char::decode_utf16([high, unit]).next() {
+                        Some(Ok(character)) => Self::Char(character),
+                        _ => return None
+                    },
+                    None => return None
+                },
+                _ => {
+                    *high_surrogate = None;
+                    match char::from_u32(wparam as u32) {
+                        Some(character) => Self::Char(character),
+                        None => return None
+                    }
+                }
+            },
+            WM_MOUSEMOVE => Self::MouseMoved { x: signed_low_word(lparam), y: signed_high_word(lparam) },
+            WM_LBUTTONDOWN | WM_LBUTTONUP | WM_RBUTTONDOWN | WM_RBUTTONUP | WM_MBUTTONDOWN | WM_MBUTTONUP | WM_XBUTTONDOWN | WM_XBUTTONUP => {
+                let (button, state) = match msg {
+                    WM_LBUTTONDOWN => (MouseButton::Left, ButtonState::Pressed),
+                    WM_LBUTTONUP => (MouseButton::Left, ButtonState::Released),
+                    WM_RBUTTONDOWN => (MouseButton::Right, ButtonState::Pressed),
+                    WM_RBUTTONUP => (MouseButton::Right, ButtonState::Released),
+                    WM_MBUTTONDOWN => (MouseButton::Middle, ButtonState::Pressed),
+                    WM_MBUTTONUP => (MouseButton::Middle, ButtonState::Released),
+                    _ => {
+                        let button = match high_word(wparam) {
+                            1 => MouseButton::X1,
+                            2 => MouseButton::X2,
+                            _ => return None
+                        };
+
+                        (button, if msg == WM_XBUTTONDOWN { ButtonState::Pressed } else { ButtonState::Released })
+                    }
+                };
+
+                Self::MouseButton { button, state, x: signed_low_word(lparam), y: signed_high_word(lparam) }
+            },
+            WM_MOUSEWHEEL | WM_MOUSEHWHEEL => Self::MouseWheel {
+                delta: high_word(wparam) as i16 as f32 / WHEEL_DELTA,
+                horizontal: msg == WM_MOUSEHWHEEL
+            },
+            WM_DPICHANGED => {
+                let dpi = low_word(wparam) as u32;
+
+                Self::DpiChanged { dpi, scale: dpi as f32 / DEFAULT_DPI }
+            },
+            _ => return None
+        };
+
+        Some(event)
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct EventState {
+    pub events: Vec<Event>,
+    pub high_surrogate: Option<u16>
+}
+impl EventState {
+    pub fn build() -> Self {
+        Self::default()
+    }
+
+    pub fn translate(&mut self, msg: u32, wparam: usize, lparam: isize) {
+        if let Some(event) = Event::translate(msg, wparam, lparam, &mut self.high_surrogate) {
+            self.events.push(event);
+        }
+    }
+}
+
+fn low_word(value: usize) -> u16 {
+    (value & 0xffff) as u16
+}
+
+fn high_word(value: usize) -> u16 {
+    ((value >> 16) & 0xffff) as u16
+}
+
+fn signed_low_word(value: isize) -> i32 {
+    low_word(value as usize) as i16 as i32
+}
+
+fn signed_high_word(value: isize) -> i32 {
+    high_word(value as usize) as i16 as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translate(msg: u32, wparam: usize, lparam: isize) -> Option<Event> {
+        Event::translate(msg, wparam, lparam, &mut None)
+    }
+
+    fn chars(units: &[u16]) -> Vec<Event> {
+        let mut state = EventState::build();
+        for unit in units {
+            state.translate(WM_CHAR, *unit as usize, 0);
+        }
+
+        state.events
+    }
+
+    #[test]
+    fn translates_window_messages() {
+        assert_eq!(translate(WM_SIZE, 0, (600 << 16) | 800), Some(Event::Resized { width: 800, height: 600 }));
+        assert_eq!(translate(WM_MOVE, 0, (0xfff6 << 16) | 0xffec), Some(Event::Moved { x: -20, y: -10 }));
+        assert_eq!(translate(WM_CLOSE, 0, 0), Some(Event::CloseRequested));
+        assert_eq!(translate(WM_SETFOCUS, 0, 0), Some(Event::Focus(true)));
+        assert_eq!(translate(WM_DPICHANGED, 144, 0), Some(Event::DpiChanged { dpi: 144, scale: 1.5 }));
+        assert_eq!(translate(0x0400, 0, 0), None);
+    }
+
+    #[test]
+    fn translates_keys_and_mouse() {
+        let lparam = (1 << 30) | (1 << 24) | (0x1c << 16) | 1;
+        assert_eq!(translate(WM_KEYDOWN, 0x0d, lparam), Some(Event::Key {
+            key_code: 0x0d, scan_code: 0x1c, extended: true, state: ButtonState::Pressed, repeat: true, system: false
+        }));
+        assert_eq!(translate(WM_SYSKEYUP, 0x12, lparam), Some(Event::Key {
+            key_code: 0x12, scan_code: 0x1c, extended: true, state: ButtonState::Released, repeat: false, system: true
+        }));
+
+        assert_eq!(translate(WM_XBUTTONDOWN, 2 << 16, (5 << 16) | 7), Some(Event::MouseButton { button: MouseButton::X2, state: ButtonState::Pressed, x: 7, y: 5 }));
+        assert_eq!(translate(WM_XBUTTONUP, 3 << 16, 0), None);
+        assert_eq!(translate(WM_MOUSEWHEEL, 0xff88 << 16, 0), Some(Event::MouseWheel { delta: -1.0, horizontal: false }));
+    }
+
+    #[test]
+    fn combines_surrogate_pairs() {
+        assert_eq!(chars(&[0x61, 0xd83d, 0xde00, 0x42f]), vec![Event::Char('a'), Event::Char('\u{1f600}'), Event::Char('\u{42f}')]);
+    }
+
+    #[test]
+    fn drops_unpaired_surrogates() {
+        assert_eq!(chars(&[0xde00, 0x61]), vec![Event::Char('a')]);
+        assert_eq!(chars(&[0xd83d, 0x61, 0xde00]), vec![Event::Char('a')]);
+        assert_eq!(chars(&[0xd83d, 0xd83d, 0xde00]), vec![Event::Char('\u{1f600}')]);
+    }
+}
\ No newline at end of file
@@ -1,19 +1,85 @@
+extern crate self as edx;
+
 mod dependencies;
 
+mod com;
+pub use com::{ ComPtr, UnknownVtbl };
+
+#[cfg(windows)]
 mod vtable;
+#[cfg(windows)]
 pub use vtable::VTable;
 
+#[cfg(windows)]
 mod directx;
-pub use directx::{ DirectX, DirectXGI, Direct3D };
+#[cfg(windows)]
+pub use directx::{ DirectX, DirectXGI, Direct3D, Adapter, DeviceResource, DeviceResources, DeviceState };
 
+#[cfg(windows)]
 mod window;
-pub use window::{ Window, WindowClassHandle, WindowHandle };
+#[cfg(windows)]
+pub use window::{ Window, WindowBuilder, WindowClassHandle, WindowHandle, WindowRef, TargetWindow, EventQueue, window_proc };
+
+mod tracking;
+pub use tracking::{ ScreenRect, TargetQuery, TargetSnapshot, OcclusionPolicy, OverlayTracker, TrackingUpdate };
+
+mod event;
+pub use event::{ Event, EventState, ButtonState, MouseButton, ControlFlow };
+
+mod dxbc;
+pub use dxbc::{ Dxbc, DxbcChunk, Signature, SignatureElement, ComponentType, SystemValue, ResourceDefinition, ConstantBufferDesc, ShaderVariable, ShaderType, ShaderTypeMember, VariableClass, VariableType, ResourceBinding, InputType, ShaderProgram, ProgramType, ShaderStatistics, ShaderReflection, InputElement };
 
 mod shader;
+#[cfg(windows)]
 pub use shader::Shader;
+#[cfg(all(windows, feature = "compiler"))]
+pub use shader::{ ShaderBuilder, ShaderStage, OptimizationLevel, CompileOutput, D3DInclude, ShaderWatcher };
+pub use shader::{ ShaderReload, FileWatch, FileStamp, ShaderCache, CacheKey, CacheEntry, scan_includes };
+pub use shader::{ IncludeType, IncludeFile, IncludeResolver, IncludeHandler, DirectoryInclude, MemoryInclude, CallbackInclude };
 
+#[cfg(windows)]
 mod renderer;
+#[cfg(windows)]
 pub use renderer::Renderer;
 
+mod constant_buffer;
+#[cfg(windows)]
+pub use constant_buffer::{ ConstantBuffer, DynamicBuffer };
+pub use constant_buffer::{ ConstantLayout, ConstantField, PackingError, validate_packing };
+
+mod material;
+#[cfg(windows)]
+pub use material::Material;
+pub use material::{ ShaderValue, MaterialParameters, ParameterBuffer, TextureParameter };
+
+mod color;
+pub use color::{ Color, srgb_to_linear, linear_to_srgb };
+
+mod color_output;
+pub use color_output::{ ColorOutput, ColorOutputConstants, ColorEncoding, BackbufferFormat, pq_encode };
+
+mod swapchain_config;
+pub use swapchain_config::{ SwapchainConfig, SwapEffect, SwapchainError };
+
+mod device_config;
+pub use device_config::{ DeviceConfig, DeviceAttempt, DriverType, FeatureLevel };
+
+mod device_recovery;
+pub use device_recovery::{ DeviceRemovedReason, ResourceId, ResourceDesc, ResourceRegistry };
+
+mod adapter_info;
+pub use adapter_info::{ AdapterInfo, AdapterPreference, OutputInfo, DisplayMode };
+
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+#[cfg(all(windows, feature = "diagnostics"))]
+pub use diagnostics::InfoQueue;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::{ DebugMessage, MessageSeverity, MessageCategory, MessageFilter, MessageSink, MessageDispatcher };
+
+mod projection;
+pub use projection::{ Projection, ProjectionConstants, Viewport };
+
 mod vertex;
-pub use vertex::Vertex;
\ No newline at end of file
+pub use vertex::{ Vertex, Vertex2D, PackedColor, VertexFormat, VertexElement, VertexAttribute, ElementFormat };
+pub use edx_derive::VertexFormat;
\ No newline at end of file
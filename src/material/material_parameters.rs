@@ -0,0 +1,280 @@
+use crate::{ ShaderReflection, ShaderVariable, ShaderValue, ConstantBufferDesc, InputType };
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ParameterBuffer {
+    pub name: String,
+    pub vertex_slot: Option<u32>,
+    pub pixel_slot: Option<u32>,
+    pub variables: Vec<ShaderVariable>,
+    pub data: Vec<u8>,
+    pub dirty: bool
+}
+impl ParameterBuffer {
+    pub fn write(&mut self, name: &str, value: &dyn ShaderValue) -> Option<bool> {
+        let variable = self.variables.iter().find(|variable| variable.name == name)?;
+        let bytes = match value.encode(&variable.ty) {
+            Some(bytes) if bytes.len() <= variable.size as usize => bytes,
+            _ => return Some(false)
+        };
+        let offset = variable.offset as usize;
+
+        let target = self.data.get_mut(offset..offset + bytes.len())?;
+        if target != bytes.as_slice() {
+            target.copy_from_slice(&bytes);
+            self.dirty = true;
+        }
+
+        Some(true)
+    }
+
+    fn shares_layout(&self, desc: &ConstantBufferDesc) -> bool {
+        self.name == desc.name
+            && self.data.len() == desc.size as usize
+            && self.variables.len() == desc.variables.len()
+            && self.variables.iter().zip(desc.variables.iter()).all(|(variable, other)| {
+                variable.name == other.name && variable.offset == other.offset && variable.size == other.size && variable.ty == other.ty
+            })
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TextureParameter {
+    pub name: String,
+    pub vertex_slot: Option<u32>,
+    pub pixel_slot: Option<u32>
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MaterialParameters {
+    pub buffers: Vec<ParameterBuffer>,
+    pub textures: Vec<TextureParameter>
+}
+impl MaterialParameters {
+    pub fn build(vertex: &ShaderReflection, pixel: &ShaderReflection) -> Self {
+        let mut parameters = Self::default();
+        parameters.add_stage(vertex, true);
+        parameters.add_stage(pixel, false);
+
+        parameters
+    }
+
+    pub fn set(&mut self, name: &str, value: &dyn ShaderValue) -> bool {
+        let mut found = false;
+        let mut written = true;
+        for buffer in self.buffers.iter_mut() {
+            if let Some(buffer_written) = buffer.write(name, value) {
+                found = true;
+                written &= buffer_written;
+            }
+        }
+
+        found && written
+    }
+
+    pub fn texture(&self, name: &str) -> Option<usize> {
+        self.textures.iter().position(|texture| texture.name == name)
+    }
+
+    fn add_stage(&mut self, reflection: &ShaderReflection, vertex: bool) {
+        for binding in reflection.bindings() {
+            match binding.input_type {
+                InputType::ConstantBuffer => {
+                    let desc = match reflection.constant_buffers().iter().find(|desc| desc.name == binding.name) {
+                        Some(desc) => desc,
+                        None => continue
+                    };
+                    let shared = self.buffers.iter().position(|buffer| {
+                        buffer.shares_layout(desc) && if vertex { buffer.vertex_slot.is_none() } else { buffer.pixel_slot.is_none() }
+                    });
+                    let index = match shared {
+                        Some(index) => index,
+                        None => {
+                            self.buffers.push(ParameterBuffer {
+                                name: desc.name.clone(),
+                                vertex_slot: None,
+                                pixel_slot: None,
+                                variables: desc.variables.clone(),
+                                data: Self::initial_data(desc.size as usize, &desc.variables),
+                                dirty: true
+                            });
+                            self.buffers.len() - 1
+                        }
+                    };
+                    let buffer = &mut self.buffers[index];
+                    if vertex { buffer.vertex_slot = Some(binding.bind_point) } else { buffer.pixel_slot = Some(binding.bind_point) };
+                },
+                InputType::Texture => {
+                    let index = match self.texture(&binding.name) {
+                        Some(index) => index,
+                        None => {
+                            self.textures.push(TextureParameter { name: binding.name.clone(), vertex_slot: None, pixel_slot: None });
+                            self.textures.len() - 1
+                        }
+                    };
+                    let texture = &mut self.textures[index];
+                    if vertex { texture.vertex_slot = Some(binding.bind_point) } else { texture.pixel_slot = Some(binding.bind_point) };
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn initial_data(size: usize, variables: &[ShaderVariable]) -> Vec<u8> {
+        let mut data = vec![0; size];
+
+        for variable in variables {
+            let default_value = match variable.default_value.as_ref() {
+                Some(default_value) => default_value,
+                None => continue
+            };
+            let offset = variable.offset as usize;
+            if let Some(target) = data.get_mut(offset..offset + default_value.len()) {
+                target.copy_from_slice(default_value);
+            }
+        }
+
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ Signature, ShaderType, VariableClass, VariableType, ResourceDefinition, ResourceBinding, ProgramType };
+
+    fn float_type(class: VariableClass, rows: u16, columns: u16) -> ShaderType {
+        ShaderType { name: None, class, ty: VariableType::Float, rows, columns, elements: 0, members: vec![] }
+    }
+
+    fn variable(name: &str, offset: u32, ty: ShaderType) -> ShaderVariable {
+        let size = ty.rows as u32 * ty.columns as u32 * 4;
+        ShaderVariable { name: name.to_string(), offset, size, flags: ShaderVariable::USED, ty, default_value: None }
+    }
+
+    fn binding(name: &str, input_type: InputType, bind_point: u32) -> ResourceBinding {
+        ResourceBinding { name: name.to_string(), input_type, return_type: 0, dimension: 0, sample_count: 0, bind_point, bind_count: 1, flags: 0 }
+    }
+
+    fn reflection(program_type: ProgramType, constant_buffers: Vec<(ConstantBufferDesc, u32)>, textures: &[(&str, u32)]) -> ShaderReflection {
+        let mut bindings: Vec<ResourceBinding> = constant_buffers.iter().map(|(desc, slot)| binding(&desc.name, InputType::ConstantBuffer, *slot)).collect();
+        bindings.extend(textures.iter().map(|(name, slot)| binding(name, InputType::Texture, *slot)));
+
+        ShaderReflection {
+            inputs: Signature::default(),
+            outputs: Signature::default(),
+            resources: Some(ResourceDefinition {
+                program_type,
+                major: 4,
+                minor: 0,
+                flags: 0,
+                creator: String::new(),
+                constant_buffers: constant_buffers.into_iter().map(|(desc, _)| desc).collect(),
+                bindings
+            }),
+            program: None,
+            statistics: None
+        }
+    }
+
+    fn buffer(name: &str, size: u32, variables: Vec<ShaderVariable>) -> ConstantBufferDesc {
+        ConstantBufferDesc { name: name.to_string(), size, flags: 0, buffer_type: 0, variables }
+    }
+
+    fn globals() -> ConstantBufferDesc {
+        buffer("Globals", 80, vec![
+            variable("transform", 0, float_type(VariableClass::MatrixColumns, 4, 4)),
+            variable("tint", 64, float_type(VariableClass::Vector, 1, 4))
+        ])
+    }
+
+    #[test]
+    fn merges_identical_buffers_across_stages() {
+        let mut pixel_globals = globals();
+        pixel_globals.variables[0].flags = 0;
+        let parameters = MaterialParameters::build(
+            &reflection(ProgramType::Vertex, vec![(globals(), 1)], &[("atlas", 0)]),
+            &reflection(ProgramType::Pixel, vec![(pixel_globals, 2)], &[("atlas", 3)])
+        );
+
+        assert_eq!(parameters.buffers.len(), 1);
+        assert_eq!((parameters.buffers[0].vertex_slot, parameters.buffers[0].pixel_slot), (Some(1), Some(2)));
+        assert_eq!(parameters.buffers[0].data.len(), 80);
+        assert_eq!(parameters.textures, vec![TextureParameter { name: "atlas".to_string(), vertex_slot: Some(0), pixel_slot: Some(3) }]);
+        assert_eq!(parameters.texture("atlas"), Some(0));
+        assert_eq!(parameters.texture("missing"), None);
+    }
+
+    #[test]
+    fn keeps_differing_layouts_separate() {
+        let pixel_globals = buffer("Globals", 16, vec![variable("exposure", 0, float_type(VariableClass::Scalar, 1, 1))]);
+        let mut parameters = MaterialParameters::build(
+            &reflection(ProgramType::Vertex, vec![(globals(), 0)], &[]),
+            &reflection(ProgramType::Pixel, vec![(pixel_globals, 0)], &[])
+        );
+
+        assert_eq!(parameters.buffers.len(), 2);
+        assert_eq!((parameters.buffers[0].vertex_slot, parameters.buffers[0].pixel_slot), (Some(0), None));
+        assert_eq!((parameters.buffers[1].vertex_slot, parameters.buffers[1].pixel_slot), (None, Some(0)));
+        assert_eq!(parameters.buffers[1].data.len(), 16);
+
+        assert!(parameters.set("exposure", &2.0f32));
+        assert_eq!(parameters.buffers[1].data[0..4], 2.0f32.to_le_bytes());
+        assert!(parameters.set("tint", &[1.0f32, 0.5, 0.25, 1.0]));
+    }
+
+    #[test]
+    fn writes_values_and_tracks_dirty_buffers() {
+        let mut globals = globals();
+        globals.variables[1].default_value = Some([1.0f32; 4].iter().flat_map(|value| value.to_le_bytes()).collect());
+        let mut parameters = MaterialParameters::build(&reflection(ProgramType::Vertex, vec![(globals, 0)], &[]), &reflection(ProgramType::Pixel, vec![], &[]));
+        assert_eq!(parameters.buffers[0].data[64..68], 1.0f32.to_le_bytes());
+        assert!(parameters.buffers[0].dirty);
+
+        parameters.buffers[0].dirty = false;
+        assert!(parameters.set("tint", &[1.0f32; 4]));
+        assert!(!parameters.buffers[0].dirty);
+
+        assert!(parameters.set("tint", &[0.5f32, 1.0, 1.0, 1.0]));
+        assert!(parameters.buffers[0].dirty);
+        assert_eq!(parameters.buffers[0].data[64..68], 0.5f32.to_le_bytes());
+
+        assert!(!parameters.set("tint", &1.0f32));
+        assert!(!parameters.set("tint", &[1i32; 4]));
+        assert!(!parameters.set("missing", &1.0f32));
+    }
+
+    #[test]
+    fn encodes_scalars_and_vectors() {
+        let scalar = float_type(VariableClass::Scalar, 1, 1);
+        let vector = float_type(VariableClass::Vector, 1, 3);
+
+        assert_eq!(1.5f32.encode(&scalar), Some(1.5f32.to_le_bytes().to_vec()));
+        assert_eq!([1.0f32, 2.0, 3.0].encode(&vector).map(|bytes| bytes.len()), Some(12));
+        assert_eq!([1.0f32, 2.0].encode(&vector), None);
+        assert_eq!([1.0f32].encode(&scalar), Some(1.0f32.to_le_bytes().to_vec()));
+        assert_eq!(1.0f32.encode(&vector), None);
+        assert_eq!(1i32.encode(&scalar), None);
+        assert_eq!(1.0f32.encode(&ShaderType { elements: 2, ..scalar.clone() }), None);
+
+        let int = ShaderType { ty: VariableType::Int, ..scalar.clone() };
+        assert_eq!((-1i32).encode(&int), Some(vec![0xff; 4]));
+        assert_eq!(true.encode(&ShaderType { ty: VariableType::Bool, ..scalar.clone() }), Some(vec![1, 0, 0, 0]));
+        assert_eq!([7u32, 8].encode(&ShaderType { ty: VariableType::Uint, columns: 2, ..vector }), Some(vec![7, 0, 0, 0, 8, 0, 0, 0]));
+    }
+
+    #[test]
+    fn encodes_matrices_by_class() {
+        let matrix = [[1.0f32, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]];
+        let floats = |bytes: Vec<u8>| bytes.chunks(4).map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap())).collect::<Vec<f32>>();
+
+        let rows = matrix.encode(&float_type(VariableClass::MatrixRows, 4, 4)).unwrap();
+        assert_eq!(floats(rows)[0..5], [1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let columns = matrix.encode(&float_type(VariableClass::MatrixColumns, 4, 4)).unwrap();
+        assert_eq!(floats(columns)[0..5], [1.0, 5.0, 9.0, 13.0, 2.0]);
+
+        assert_eq!(matrix.encode(&float_type(VariableClass::Vector, 4, 4)), None);
+        assert_eq!(matrix.encode(&float_type(VariableClass::MatrixRows, 3, 4)), None);
+        assert_eq!(matrix.encode(&ShaderType { ty: VariableType::Int, ..float_type(VariableClass::MatrixRows, 4, 4) }), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+mod shader_value;
+pub use shader_value::ShaderValue;
+
+mod material_parameters;
+pub use material_parameters::{ MaterialParameters, ParameterBuffer, TextureParameter };
+
+#[cfg(windows)]
+use crate::dependencies::{
+    null_mut, ID3D11Device, ID3D11DeviceContext, ID3D11ShaderResourceView
+};
+
+#[cfg(windows)]
+use crate::{ ComPtr, Shader, DynamicBuffer, Vertex2D, VertexFormat };
+
+#[cfg(windows)]
+#[derive(Clone)]
+pub struct Material<V: VertexFormat = Vertex2D> {
+    pub shader: Shader<V>,
+    pub parameters: MaterialParameters,
+    pub buffers: Vec<DynamicBuffer>,
+    pub textures: Vec<Option<ComPtr<ID3D11ShaderResourceView>>>
+}
+#[cfg(windows)]
+impl<V: VertexFormat> Material<V> {
+    pub fn create(shader: Shader<V>, device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        let parameters = MaterialParameters::build(&shader.vertex_reflection, &shader.pixel_reflection);
+
+        let mut buffers = Vec::with_capacity(parameters.buffers.len());
+        for buffer in parameters.buffers.iter() {
+            buffers.push(DynamicBuffer::create(device, &buffer.data)?);
+        }
+        let textures = vec![None; parameters.textures.len()];
+
+        Some(Self { shader, parameters, buffers, textures })
+    }
+
+    pub fn set(&mut self, name: &str, value: impl ShaderValue) -> bool {
+        self.parameters.set(name, &value)
+    }
+
+    pub fn set_texture(&mut self, name: &str, texture: &ComPtr<ID3D11ShaderResourceView>) -> bool {
+        match self.parameters.texture(name) {
+            Some(index) => {
+                self.textures[index] = Some(texture.clone());
+                true
+            },
+            None => false
+        }
+    }
+
+    pub fn setup(&mut self, context: &ComPtr<ID3D11DeviceContext>) {
+        self.shader.setup(context);
+
+        for (parameter, buffer) in self.parameters.buffers.iter_mut().zip(self.buffers.iter()) {
+            if parameter.dirty && buffer.update(context, &parameter.data) {
+                parameter.dirty = false;
+            }
+
+            if let Some(slot) = parameter.vertex_slot { buffer.bind_vertex(context, slot) };
+            if let Some(slot) = parameter.pixel_slot { buffer.bind_pixel(context, slot) };
+        }
+
+        for (parameter, texture) in self.parameters.textures.iter().zip(self.textures.iter()) {
+            let view = texture.as_ref().map_or(null_mut(), |texture| texture.as_ptr());
+
+            unsafe {
+                if let Some(slot) = parameter.vertex_slot { context.VSSetShaderResources(slot, 1, &view) };
+                if let Some(slot) = parameter.pixel_slot { context.PSSetShaderResources(slot, 1, &view) };
+            }
+        }
+    }
+}
+#[cfg(windows)]
+unsafe impl<V: VertexFormat> Send for Material<V> {}
+#[cfg(windows)]
+unsafe impl<V: VertexFormat> Sync for Material<V> {}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use crate::{ ShaderType, VariableClass, VariableType };
+
+pub trait ShaderValue {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>>;
+}
+
+fn encode_components(components: &[u32], ty: &ShaderType, expected: VariableType) -> Option<Vec<u8>> {
+    let class_matches = match components.len() {
+        1 => ty.class == VariableClass::Scalar,
+        _ => ty.class == VariableClass::Vector
+    };
+    if ty.ty != expected || !class_matches || ty.elements != 0 || ty.rows != 1 || ty.columns as usize != components.len() {
+        return None;
+    }
+
+    Some(components.iter().flat_map(|component| component.to_le_bytes()).collect())
+}
+
+impl ShaderValue for f32 {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        encode_components(&[self.to_bits()], ty, VariableType::Float)
+    }
+}
+
+impl<const N: usize> ShaderValue for [f32; N] {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        let components: Vec<u32> = self.iter().map(|component| component.to_bits()).collect();
+        encode_components(&components, ty, VariableType::Float)
+    }
+}
+
+impl ShaderValue for i32 {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        encode_components(&[*self as u32], ty, VariableType::Int)
+    }
+}
+
+impl<const N: usize> ShaderValue for [i32; N] {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        let components: Vec<u32> = self.iter().map(|component| *component as u32).collect();
+        encode_components(&components, ty, VariableType::Int)
+    }
+}
+
+impl ShaderValue for u32 {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        encode_components(&[*self], ty, VariableType::Uint)
+    }
+}
+
+impl<const N: usize> ShaderValue for [u32; N] {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        encode_components(self, ty, VariableType::Uint)
+    }
+}
+
+impl ShaderValue for bool {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        encode_components(&[*self as u32], ty, VariableType::Bool)
+    }
+}
+
+impl ShaderValue for [[f32; 4]; 4] {
+    fn encode(&self, ty: &ShaderType) -> Option<Vec<u8>> {
+        if ty.ty != VariableType::Float || ty.rows != 4 || ty.columns != 4 || ty.elements != 0 { return None };
+
+        let matrix = match ty.class {
+            VariableClass::MatrixRows => *self,
+            VariableClass::MatrixColumns => {
+                let mut transposed = [[0.0; 4]; 4];
+                for (row, values) in self.iter().enumerate() {
+                    for (column, value) in values.iter().enumerate() {
+                        transposed[column][row] = *value;
+                    }
+                }
+                transposed
+            },
+            _ => return None
+        };
+
+        Some(matrix.iter().flatten().flat_map(|component| component.to_le_bytes()).collect())
+    }
+}
\ No newline at end of file
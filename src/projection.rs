@@ -0,0 +1,150 @@
+use crate::{ constant_layout };
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Viewport {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32
+}
+impl Viewport {
+    pub fn build(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn full(resolution: [u32; 2]) -> Self {
+        Self::build(0.0, 0.0, resolution[0] as f32, resolution[1] as f32)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ProjectionConstants {
+    pub scale: [f32; 2],
+    pub offset: [f32; 2]
+}
+constant_layout!(ProjectionConstants { scale, offset });
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Projection {
+    pub viewport: Viewport,
+    pub dpi_scale: f32
+}
+impl Projection {
+    pub fn build(resolution: [u32; 2]) -> Self {
+        Self { viewport: Viewport::full(resolution), dpi_scale: 1.0 }
+    }
+
+    pub fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    pub fn dpi_scale(mut self, dpi_scale: f32) -> Self {
+        self.dpi_scale = dpi_scale;
+        self
+    }
+
+    pub fn resize(mut self, previous: [u32; 2], resolution: [u32; 2]) -> Self {
+        if self.viewport == Viewport::full(previous) {
+            self.viewport = Viewport::full(resolution);
+        }
+        self
+    }
+
+    pub fn constants(&self) -> ProjectionConstants {
+        if self.viewport.is_empty() {
+            return ProjectionConstants::default();
+        }
+
+        ProjectionConstants {
+            scale: [
+                2.0 * self.dpi_scale / self.viewport.width,
+                -2.0 * self.dpi_scale / self.viewport.height
+            ],
+            offset: [-1.0, 1.0]
+        }
+    }
+
+    pub fn to_ndc(&self, position: [f32; 2]) -> [f32; 2] {
+        let constants = self.constants();
+
+        [
+            position[0] * constants.scale[0] + constants.offset[0],
+            position[1] * constants.scale[1] + constants.offset[1]
+        ]
+    }
+
+    pub fn to_target(&self, position: [f32; 2]) -> [f32; 2] {
+        [
+            self.viewport.x + position[0] * self.dpi_scale,
+            self.viewport.y + position[1] * self.dpi_scale
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConstantLayout;
+
+    #[test]
+    fn maps_full_viewport_corners() {
+        let projection = Projection::build([800, 600]);
+
+        assert_eq!(projection.constants(), ProjectionConstants { scale: [2.0 / 800.0, -2.0 / 600.0], offset: [-1.0, 1.0] });
+        assert_eq!(projection.to_ndc([0.0, 0.0]), [-1.0, 1.0]);
+        assert_eq!(projection.to_ndc([800.0, 600.0]), [1.0, -1.0]);
+        assert_eq!(projection.to_ndc([400.0, 300.0]), [0.0, 0.0]);
+        assert_eq!(projection.to_target([400.0, 300.0]), [400.0, 300.0]);
+    }
+
+    #[test]
+    fn maps_relative_to_sub_viewports() {
+        let projection = Projection::build([800, 600]).viewport(Viewport::build(100.0, 50.0, 200.0, 100.0));
+
+        assert_eq!(projection.to_ndc([0.0, 0.0]), [-1.0, 1.0]);
+        assert_eq!(projection.to_ndc([200.0, 100.0]), [1.0, -1.0]);
+        assert_eq!(projection.to_target([0.0, 0.0]), [100.0, 50.0]);
+        assert_eq!(projection.to_target([200.0, 100.0]), [300.0, 150.0]);
+    }
+
+    #[test]
+    fn scales_logical_units_by_dpi() {
+        let projection = Projection::build([800, 600]).dpi_scale(2.0);
+
+        assert_eq!(projection.constants().scale, [4.0 / 800.0, -4.0 / 600.0]);
+        assert_eq!(projection.to_ndc([400.0, 300.0]), [1.0, -1.0]);
+        assert_eq!(projection.to_target([400.0, 300.0]), [800.0, 600.0]);
+
+        let projection = projection.viewport(Viewport::build(100.0, 50.0, 400.0, 200.0));
+        assert_eq!(projection.to_ndc([200.0, 100.0]), [1.0, -1.0]);
+        assert_eq!(projection.to_target([10.0, 20.0]), [120.0, 90.0]);
+    }
+
+    #[test]
+    fn empty_viewports_collapse() {
+        for viewport in [Viewport::build(0.0, 0.0, 0.0, 600.0), Viewport::build(10.0, 10.0, 800.0, -1.0)] {
+            let projection = Projection::build([800, 600]).viewport(viewport);
+
+            assert!(viewport.is_empty());
+            assert_eq!(projection.constants(), ProjectionConstants::default());
+            assert_eq!(projection.to_ndc([100.0, 100.0]), [0.0, 0.0]);
+        }
+        assert_eq!(ProjectionConstants::validate(), Ok(()));
+    }
+
+    #[test]
+    fn resize_keeps_custom_viewports() {
+        let projection = Projection::build([800, 600]).resize([800, 600], [1920, 1080]);
+        assert_eq!(projection.viewport, Viewport::full([1920, 1080]));
+
+        let viewport = Viewport::build(100.0, 50.0, 200.0, 100.0);
+        let projection = Projection::build([800, 600]).viewport(viewport).resize([800, 600], [1920, 1080]);
+        assert_eq!(projection.viewport, viewport);
+    }
+}
\ No newline at end of file
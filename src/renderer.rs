@@ -1,44 +1,58 @@
 use crate::dependencies::{
-    null_mut, size_of, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, ID3D11RenderTargetView, ID3D11DepthStencilView, ID3D11Resource, ID3D11Buffer, D3D11_BUFFER_DESC, D3D11_BIND_VERTEX_BUFFER, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT, D3D11_BIND_INDEX_BUFFER, UINT, DXGI_FORMAT_R16_UINT, DXGI_FORMAT_R32_UINT
+    null_mut, zeroed, size_of, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11Texture2D, ID3D11RenderTargetView, ID3D11DepthStencilView, ID3D11Resource, ID3D11Buffer, D3D11_BUFFER_DESC, D3D11_BIND_VERTEX_BUFFER, D3D11_SUBRESOURCE_DATA, D3D11_USAGE_DEFAULT, D3D11_BIND_INDEX_BUFFER, D3D11_VIEWPORT, D3D11_RENDER_TARGET_VIEW_DESC, D3D11_TEXTURE2D_DESC, D3D11_RTV_DIMENSION_TEXTURE2D, D3D11_RTV_DIMENSION_TEXTURE2DMS, ID3D11BlendState, D3D11_BLEND_DESC, D3D11_RENDER_TARGET_BLEND_DESC, D3D11_BLEND_ONE, D3D11_BLEND_SRC_ALPHA, D3D11_BLEND_INV_SRC_ALPHA, D3D11_BLEND_OP_ADD, D3D11_COLOR_WRITE_ENABLE_ALL, UINT, DXGI_FORMAT_R16_UINT, DXGI_FORMAT_R32_UINT
 };
 
-use crate::{ Vertex, Direct3D };
+use crate::{ Vertex2D, PackedColor, Color, VertexFormat, Direct3D, ComPtr, ConstantBuffer, Projection, ProjectionConstants, Viewport, ColorOutput, ColorOutputConstants };
 
 #[derive(Clone)]
-pub struct Renderer {
-    pub device: *mut ID3D11Device,
-    pub context: *mut ID3D11DeviceContext,
-    pub backbuffer: *mut ID3D11Texture2D,
+pub struct Renderer<V: VertexFormat = Vertex2D> {
+    pub device: ComPtr<ID3D11Device>,
+    pub context: ComPtr<ID3D11DeviceContext>,
+    pub backbuffer: Option<ComPtr<ID3D11Texture2D>>,
     pub resolution: [u32; 2],
-    pub game_rtv: *mut ID3D11RenderTargetView,
-    pub game_dsv: *mut ID3D11DepthStencilView,
-    pub rtv: *mut ID3D11RenderTargetView,
-    pub dsv: *mut ID3D11DepthStencilView,
-    pub vertex_buffer: *mut ID3D11Buffer,
+    pub projection: Projection,
+    pub projection_buffer: Option<ConstantBuffer<ProjectionConstants>>,
+    pub game_viewport: Option<D3D11_VIEWPORT>,
+    pub color_output: ColorOutput,
+    pub color_buffer: Option<ConstantBuffer<ColorOutputConstants>>,
+    pub blend_state: Option<ComPtr<ID3D11BlendState>>,
+    pub game_blend: Option<(Option<ComPtr<ID3D11BlendState>>, [f32; 4], UINT)>,
+    pub game_rtv: Option<ComPtr<ID3D11RenderTargetView>>,
+    pub game_dsv: Option<ComPtr<ID3D11DepthStencilView>>,
+    pub rtv: Option<ComPtr<ID3D11RenderTargetView>>,
+    pub dsv: Option<ComPtr<ID3D11DepthStencilView>>,
+    pub vertex_buffer: Option<ComPtr<ID3D11Buffer>>,
     pub vertex_stride: u32,
     pub vertex_count: u32,
-    pub index_buffer: *mut ID3D11Buffer,
+    pub index_buffer: Option<ComPtr<ID3D11Buffer>>,
     pub index_count: u32,
-    pub vertices: Vec<Vertex>,
+    pub vertices: Vec<V>,
     pub indices: Vec<UINT>
 }
-impl Renderer {
+impl<V: VertexFormat> Renderer<V> {
     pub fn create(d3d: &Direct3D) -> Self {
-        let device = d3d.device;
-        let context = d3d.context;
-        let backbuffer = d3d.backbuffer;
+        let device = d3d.device.clone();
+        let context = d3d.context.clone();
+        let backbuffer = d3d.backbuffer.clone();
         let resolution = d3d.resolution;
-        let (game_rtv, game_dsv) = Self::get_render_targets(context);
-        let (rtv, dsv) = (null_mut::<ID3D11RenderTargetView>(), null_mut::<ID3D11DepthStencilView>());
-        let vertex_buffer = null_mut();
-        let vertex_stride = size_of::<Vertex>() as u32;
+        let projection = Projection::build(resolution);
+        let projection_buffer = None;
+        let game_viewport = None;
+        let color_output = ColorOutput::detect(d3d.desc.BufferDesc.Format);
+        let color_buffer = None;
+        let blend_state = None;
+        let game_blend = None;
+        let (game_rtv, game_dsv) = (None, None);
+        let (rtv, dsv) = (None, None);
+        let vertex_buffer = None;
+        let vertex_stride = V::stride();
         let vertex_count = 0;
-        let index_buffer = null_mut();
+        let index_buffer = None;
         let index_count = 0;
-        let vertices: Vec<Vertex> = vec![];
+        let vertices: Vec<V> = vec![];
         let indices: Vec<UINT> = vec![];
 
-        Self { device, context, backbuffer, resolution, game_rtv, game_dsv, rtv, dsv, vertex_buffer, vertex_stride, vertex_count, index_buffer, index_count, vertices, indices }
+        Self { device, context, backbuffer, resolution, projection, projection_buffer, game_viewport, color_output, color_buffer, blend_state, game_blend, game_rtv, game_dsv, rtv, dsv, vertex_buffer, vertex_stride, vertex_count, index_buffer, index_count, vertices, indices }
     }
 
     pub fn setup(&mut self) {
@@ -49,38 +63,45 @@ impl Renderer {
         self.release_rtv();
     }
 
+    pub fn release_backbuffer(&mut self) {
+        self.release_rtv();
+        self.backbuffer = None;
+    }
+
+    pub fn acquire_backbuffer(&mut self, backbuffer: &ComPtr<ID3D11Texture2D>, resolution: [u32; 2]) {
+        self.backbuffer = Some(backbuffer.clone());
+        self.set_resolution(resolution);
+        self.create_rtv();
+    }
+
     pub fn flush(&mut self) {
         if !self.vertices.is_empty() && !self.indices.is_empty() {
-            if !self.vertex_buffer.is_null() {
-                unsafe { (*self.vertex_buffer).Release(); }
-                self.vertex_buffer = null_mut();
-            }
-            if !self.index_buffer.is_null() {
-                unsafe { (*self.index_buffer).Release(); }
-                self.index_buffer = null_mut();
-            }
+            self.vertex_buffer = None;
+            self.index_buffer = None;
 
             self.vertex_buffer = match self.create_vertex_buffer(&self.vertices) {
-                Some(vertex_buffer) => vertex_buffer,
+                Some(vertex_buffer) => Some(vertex_buffer),
                 None => return
             };
             self.index_buffer = match self.create_index_buffer(&self.indices) {
-                Some(index_buffer) => index_buffer,
+                Some(index_buffer) => Some(index_buffer),
                 None => return
             };
 
             self.vertex_count = self.vertices.len() as u32;
             self.index_count = self.indices.len() as u32;
-            self.vertex_stride = size_of::<Vertex>() as u32;
+            self.vertex_stride = V::stride();
+
+            let vertex_buffer = ComPtr::as_ptr_or_null(&self.vertex_buffer);
 
             unsafe {
-                (*self.context).IASetVertexBuffers(0, 1, &self.vertex_buffer, &self.vertex_stride, &0);
-                (*self.context).IASetIndexBuffer(
-                    self.index_buffer,
+                self.context.IASetVertexBuffers(0, 1, &vertex_buffer, &self.vertex_stride, &0);
+                self.context.IASetIndexBuffer(
+                    ComPtr::as_ptr_or_null(&self.index_buffer),
                     DXGI_FORMAT_R32_UINT,
                     0,
                 );
-                (*self.context).DrawIndexed(self.index_count, 0, 0);
+                self.context.DrawIndexed(self.index_count, 0, 0);
             }
         
             self.vertices.clear();
@@ -91,148 +112,109 @@ impl Renderer {
     }
 
     pub fn set_own_render(&mut self) {
-        unsafe { (*self.context).OMSetRenderTargets(1, &self.rtv, self.dsv) };
-    }
+        self.game_viewport = self.get_viewport();
+        (self.game_rtv, self.game_dsv) = Self::get_render_targets(&self.context);
 
-    pub fn set_game_render(&mut self) {
-        unsafe { (*self.context).OMSetRenderTargets(1, &self.game_rtv, self.game_dsv) };
-    }
+        let rtv = ComPtr::as_ptr_or_null(&self.rtv);
+        unsafe { self.context.OMSetRenderTargets(1, &rtv, ComPtr::as_ptr_or_null(&self.dsv)) };
 
-    pub fn draw_background(&self, color: [f32; 4]) {
-        unsafe { (*self.context).ClearRenderTargetView(self.rtv, &color) };
+        self.apply_projection();
+        self.apply_color_output();
+        self.apply_blend_state();
     }
 
-    pub fn draw_line(&mut self, start: [f32; 2], end: [f32; 2], color: [f32; 4], thickness: f32) {
-        let dx = end[0] - start[0];
-        let dy = end[1] - start[1];
-        let len = (dx * dx + dy * dy).sqrt();
-
-        if len == 0.0 { return };
-
-        let nx = -dy / len;
-        let ny =  dx / len;
-
-        let offset_x = nx * (thickness / 2.0);
-        let offset_y = ny * (thickness / 2.0);
-
-        let v0 = [start[0] - offset_x, start[1] - offset_y];
-        let v1 = [end[0]   - offset_x, end[1]   - offset_y];
-        let v2 = [end[0]   + offset_x, end[1]   + offset_y];
-        let v3 = [start[0] + offset_x, start[1] + offset_y];
-        
-        let v0 = self.position_to_ndc(v0);
-        let v1 = self.position_to_ndc(v1);
-        let v2 = self.position_to_ndc(v2);
-        let v3 = self.position_to_ndc(v3);
-
-        let base_index = self.vertices.len() as u32;
+    pub fn set_game_render(&mut self) {
+        let game_rtv = ComPtr::as_ptr_or_null(&self.game_rtv);
+        unsafe { self.context.OMSetRenderTargets(1, &game_rtv, ComPtr::as_ptr_or_null(&self.game_dsv)) };
+        (self.game_rtv, self.game_dsv) = (None, None);
 
-        self.vertices.extend_from_slice(&[
-            Vertex { position: [v0.0, v0.1, 0.0], color },
-            Vertex { position: [v1.0, v1.1, 0.0], color },
-            Vertex { position: [v2.0, v2.1, 0.0], color },
-            Vertex { position: [v3.0, v3.1, 0.0], color }
-        ]);
+        if let Some(viewport) = self.game_viewport.take() {
+            unsafe { self.context.RSSetViewports(1, &viewport) };
+        }
 
-        self.indices.extend_from_slice(&[
-            base_index, base_index + 1, base_index + 2,
-            base_index, base_index + 2, base_index + 3
-        ]);
+        if let Some((blend_state, blend_factor, sample_mask)) = self.game_blend.take() {
+            unsafe { self.context.OMSetBlendState(ComPtr::as_ptr_or_null(&blend_state), &blend_factor, sample_mask) };
+        }
     }
 
-    pub fn draw_rect(&mut self, start: [f32; 2], end: [f32; 2], color: [f32; 4], thickness: f32) {
-        let x1 = start[0];
-        let y1 = start[1];
-        let x2 = end[0];
-        let y2 = end[1];
-
-        let top_left     = [x1, y1];
-        let top_right    = [x2, y1];
-        let bottom_right = [x2, y2];
-        let bottom_left  = [x1, y2];
-        
-        self.draw_line(top_left, top_right, color, thickness);
-        self.draw_line(top_right, bottom_right, color, thickness);
-        self.draw_line(bottom_right, bottom_left, color, thickness);
-        self.draw_line(bottom_left, top_left, color, thickness);
+    pub fn set_projection(&mut self, projection: Projection) {
+        self.projection = projection;
     }
 
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.projection.viewport = viewport;
+    }
 
-    pub fn draw_rect_filled(&mut self, start: [f32; 2], end: [f32; 2], color: [f32; 4]) {
-        let x1 = start[0];
-        let y1 = start[1];
-        let x2 = end[0];
-        let y2 = end[1];
+    pub fn set_dpi_scale(&mut self, dpi_scale: f32) {
+        self.projection.dpi_scale = dpi_scale;
+    }
 
-        let v0 = [x1, y1];
-        let v1 = [x2, y1];
-        let v2 = [x2, y2];
-        let v3 = [x1, y2];
+    pub fn set_color_output(&mut self, color_output: ColorOutput) {
+        if color_output.rtv_format() != self.color_output.rtv_format() {
+            self.release_rtv();
+            self.color_output = color_output;
+            self.create_rtv();
+        } else {
+            self.color_output = color_output;
+        }
+    }
 
-        let v0 = self.position_to_ndc(v0);
-        let v1 = self.position_to_ndc(v1);
-        let v2 = self.position_to_ndc(v2);
-        let v3 = self.position_to_ndc(v3);
+    pub fn set_premultiplied_alpha(&mut self, enabled: bool) {
+        self.blend_state = match enabled {
+            true => self.create_premultiplied_blend_state(),
+            false => None
+        };
+    }
 
-        let base_index = self.vertices.len() as u32;
+    pub fn set_paper_white(&mut self, nits: f32) {
+        self.color_output.paper_white_nits = nits;
+    }
 
-        self.vertices.extend_from_slice(&[
-            Vertex { position: [v0.0, v0.1, 0.0], color },
-            Vertex { position: [v1.0, v1.1, 0.0], color },
-            Vertex { position: [v2.0, v2.1, 0.0], color },
-            Vertex { position: [v3.0, v3.1, 0.0], color }
-        ]);
-        
-        self.indices.extend_from_slice(&[
-            base_index, base_index + 1, base_index + 2,
-            base_index, base_index + 2, base_index + 3
-        ]);
+    pub fn set_resolution(&mut self, resolution: [u32; 2]) {
+        self.projection = self.projection.resize(self.resolution, resolution);
+        self.resolution = resolution;
     }
 
-    fn position_to_ndc(&self, position: [f32; 2]) -> (f32, f32) {
-        let ndc_x = (2.0 * position[0] / self.resolution[0] as f32) - 1.0;
-        let ndc_y = 1.0 - (2.0 * position[1] / self.resolution[1] as f32);
-        (ndc_x, ndc_y)
+    pub fn draw_background(&self, color: impl Into<Color>) {
+        let color = self.color_output.encode(color.into());
+        if let Some(rtv) = self.rtv.as_ref() {
+            unsafe { self.context.ClearRenderTargetView(rtv.as_ptr(), &color) };
+        }
     }
 
-    fn thickness_to_ndc(&self, thickness: f32) -> (f32, f32) {
-        let px_to_ndc_x = 2.0 / self.resolution[0] as f32;
-        let px_to_ndc_y = 2.0 / self.resolution[1] as f32;
+    pub fn push(&mut self, vertices: &[V], indices: &[UINT]) {
+        let base_index = self.vertices.len() as u32;
 
-        (thickness * px_to_ndc_x, thickness * px_to_ndc_y)
+        self.vertices.extend_from_slice(vertices);
+        self.indices.extend(indices.iter().map(|index| base_index + index));
     }
 
-    fn set_vertices(&mut self, vertices: &[Vertex]) {
-        if !self.vertex_buffer.is_null() {
-            unsafe { (*self.vertex_buffer).Release(); }
-            self.vertex_buffer = null_mut();
-        }
+    fn set_vertices(&mut self, vertices: &[V]) {
+        self.vertex_buffer = None;
         
         self.vertex_buffer = match self.create_vertex_buffer(vertices) {
-            Some(vertex_buffer) => vertex_buffer,
+            Some(vertex_buffer) => Some(vertex_buffer),
             None => return
         };
         self.vertex_count = vertices.len() as u32;
-        self.vertex_stride = size_of::<Vertex>() as u32;
+        self.vertex_stride = V::stride();
 
-        unsafe { (*self.context).IASetVertexBuffers(0, 1, &self.vertex_buffer, &self.vertex_stride, &0) };
+        let vertex_buffer = ComPtr::as_ptr_or_null(&self.vertex_buffer);
+        unsafe { self.context.IASetVertexBuffers(0, 1, &vertex_buffer, &self.vertex_stride, &0) };
     }
 
     fn set_indices(&mut self, indices: &[UINT]) {
-        if !self.index_buffer.is_null() {
-            unsafe { (*self.index_buffer).Release(); }
-            self.index_buffer = null_mut();
-        }
+        self.index_buffer = None;
 
         self.index_buffer = match self.create_index_buffer(indices) {
-            Some(index_buffer) => index_buffer,
+            Some(index_buffer) => Some(index_buffer),
             None => return
         };
         self.index_count = indices.len() as u32;
 
         unsafe {
-            (*self.context).IASetIndexBuffer(
-                self.index_buffer,
+            self.context.IASetIndexBuffer(
+                ComPtr::as_ptr_or_null(&self.index_buffer),
                 if size_of::<UINT>() == 2 {
                     DXGI_FORMAT_R16_UINT
                 } else {
@@ -243,12 +225,12 @@ impl Renderer {
         }
     }
     
-    fn create_vertex_buffer(&self, vertices: &[Vertex]) -> Option<*mut ID3D11Buffer> {
+    fn create_vertex_buffer(&self, vertices: &[V]) -> Option<ComPtr<ID3D11Buffer>> {
         unsafe {
             let mut vertex_buffer: *mut ID3D11Buffer = null_mut();
             
             let desc = D3D11_BUFFER_DESC {
-                ByteWidth: (vertices.len() * size_of::<Vertex>()) as u32,
+                ByteWidth: vertices.len() as u32 * V::stride(),
                 Usage: D3D11_USAGE_DEFAULT,
                 BindFlags: D3D11_BIND_VERTEX_BUFFER,
                 CPUAccessFlags: 0,
@@ -262,15 +244,15 @@ impl Renderer {
                 SysMemSlicePitch: 0
             };
             
-            let hr = (*self.device).CreateBuffer(&desc, &data, &mut vertex_buffer);
+            let hr = self.device.CreateBuffer(&desc, &data, &mut vertex_buffer);
             
-            if SUCCEEDED(hr) { return Some(vertex_buffer) };
+            if SUCCEEDED(hr) { return ComPtr::from_raw(vertex_buffer) };
             
             None
         }
     }
     
-    fn create_index_buffer(&self, indices: &[UINT]) -> Option<*mut ID3D11Buffer> {
+    fn create_index_buffer(&self, indices: &[UINT]) -> Option<ComPtr<ID3D11Buffer>> {
         unsafe {
             let mut index_buffer: *mut ID3D11Buffer = null_mut();
             
@@ -289,38 +271,207 @@ impl Renderer {
                 SysMemSlicePitch: 0,
             };
             
-            let hr = (*self.device).CreateBuffer(&desc, &data, &mut index_buffer);
+            let hr = self.device.CreateBuffer(&desc, &data, &mut index_buffer);
             
-            if SUCCEEDED(hr) { return Some(index_buffer) };
+            if SUCCEEDED(hr) { return ComPtr::from_raw(index_buffer) };
             
             None
         }
     }
 
-    fn get_render_targets(context: *mut ID3D11DeviceContext) -> (*mut ID3D11RenderTargetView, *mut ID3D11DepthStencilView) {
+    fn apply_projection(&mut self) {
+        let viewport = D3D11_VIEWPORT {
+            TopLeftX: self.projection.viewport.x,
+            TopLeftY: self.projection.viewport.y,
+            Width: self.projection.viewport.width,
+            Height: self.projection.viewport.height,
+            MinDepth: 0.0,
+            MaxDepth: 1.0
+        };
+        unsafe { self.context.RSSetViewports(1, &viewport) };
+
+        let constants = self.projection.constants();
+        match self.projection_buffer.as_ref() {
+            Some(projection_buffer) => { projection_buffer.update(&self.context, &constants); },
+            None => self.projection_buffer = ConstantBuffer::create(&self.device, &constants)
+        }
+
+        if let Some(projection_buffer) = self.projection_buffer.as_ref() {
+            projection_buffer.bind_vertex(&self.context, 0);
+        }
+    }
+
+    fn apply_color_output(&mut self) {
+        let constants = self.color_output.constants();
+        match self.color_buffer.as_ref() {
+            Some(color_buffer) => { color_buffer.update(&self.context, &constants); },
+            None => self.color_buffer = ConstantBuffer::create(&self.device, &constants)
+        }
+
+        if let Some(color_buffer) = self.color_buffer.as_ref() {
+            color_buffer.bind_pixel(&self.context, 0);
+        }
+    }
+
+    fn apply_blend_state(&mut self) {
+        let blend_state = match self.blend_state.as_ref() {
+            Some(blend_state) => blend_state,
+            None => return
+        };
+
+        let mut game_blend_state = null_mut::<ID3D11BlendState>();
+        let mut game_blend_factor = [0.0; 4];
+        let mut game_sample_mask = 0;
+        unsafe { self.context.OMGetBlendState(&mut game_blend_state, &mut game_blend_factor, &mut game_sample_mask) };
+        self.game_blend = Some((unsafe { ComPtr::from_raw(game_blend_state) }, game_blend_factor, game_sample_mask));
+
+        unsafe { self.context.OMSetBlendState(blend_state.as_ptr(), &[0.0; 4], 0xffffffff) };
+    }
+
+    fn create_premultiplied_blend_state(&self) -> Option<ComPtr<ID3D11BlendState>> {
+        let mut desc = unsafe { zeroed::<D3D11_BLEND_DESC>() };
+        desc.RenderTarget[0] = D3D11_RENDER_TARGET_BLEND_DESC {
+            BlendEnable: 1,
+            SrcBlend: D3D11_BLEND_SRC_ALPHA,
+            DestBlend: D3D11_BLEND_INV_SRC_ALPHA,
+            BlendOp: D3D11_BLEND_OP_ADD,
+            SrcBlendAlpha: D3D11_BLEND_ONE,
+            DestBlendAlpha: D3D11_BLEND_INV_SRC_ALPHA,
+            BlendOpAlpha: D3D11_BLEND_OP_ADD,
+            RenderTargetWriteMask: D3D11_COLOR_WRITE_ENABLE_ALL as u8
+        };
+
+        let mut blend_state = null_mut::<ID3D11BlendState>();
+        let hr = unsafe { self.device.CreateBlendState(&desc, &mut blend_state) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(blend_state) } };
+
+        None
+    }
+
+    fn get_viewport(&self) -> Option<D3D11_VIEWPORT> {
+        let mut count = 1;
+        let mut viewport = D3D11_VIEWPORT { TopLeftX: 0.0, TopLeftY: 0.0, Width: 0.0, Height: 0.0, MinDepth: 0.0, MaxDepth: 0.0 };
+        unsafe { self.context.RSGetViewports(&mut count, &mut viewport) };
+
+        if count > 0 { Some(viewport) } else { None }
+    }
+
+    fn get_render_targets(context: &ComPtr<ID3D11DeviceContext>) -> (Option<ComPtr<ID3D11RenderTargetView>>, Option<ComPtr<ID3D11DepthStencilView>>) {
         let (mut rtv, mut dsv) = (null_mut::<ID3D11RenderTargetView>(), null_mut::<ID3D11DepthStencilView>());
 
-        unsafe { (*context).OMGetRenderTargets(1, &mut rtv, &mut dsv) };
+        unsafe { context.OMGetRenderTargets(1, &mut rtv, &mut dsv) };
 
-        (rtv, dsv)
+        unsafe { (ComPtr::from_raw(rtv), ComPtr::from_raw(dsv)) }
     }
 
     fn create_rtv(&mut self) {
-        let hr = unsafe { (*self.device).CreateRenderTargetView(
-            self.backbuffer as *mut ID3D11Resource,
-            null_mut(),
-            &mut self.rtv
+        let backbuffer = match self.backbuffer.as_ref() {
+            Some(backbuffer) => backbuffer,
+            None => return
+        };
+
+        let mut texture_desc = unsafe { zeroed::<D3D11_TEXTURE2D_DESC>() };
+        unsafe { backbuffer.GetDesc(&mut texture_desc) };
+
+        let mut desc = unsafe { zeroed::<D3D11_RENDER_TARGET_VIEW_DESC>() };
+        desc.Format = self.color_output.rtv_format();
+        desc.ViewDimension = if texture_desc.SampleDesc.Count > 1 { D3D11_RTV_DIMENSION_TEXTURE2DMS } else { D3D11_RTV_DIMENSION_TEXTURE2D };
+
+        let mut rtv = null_mut::<ID3D11RenderTargetView>();
+        let hr = unsafe { self.device.CreateRenderTargetView(
+            backbuffer.as_ptr() as *mut ID3D11Resource,
+            &desc,
+            &mut rtv
         ) };
 
-        if !SUCCEEDED(hr) { self.rtv = null_mut::<ID3D11RenderTargetView>() };
+        self.rtv = if SUCCEEDED(hr) { unsafe { ComPtr::from_raw(rtv) } } else { None };
     }
 
     fn release_rtv(&mut self) {
-        if !self.rtv.is_null() {
-            unsafe { (*self.rtv).Release() };
-            self.rtv = null_mut::<ID3D11RenderTargetView>();
-        }
+        self.rtv = None;
+    }
+}
+impl Renderer<Vertex2D> {
+    pub fn draw_line(&mut self, start: [f32; 2], end: [f32; 2], color: impl Into<Color>, thickness: f32) {
+        let dx = end[0] - start[0];
+        let dy = end[1] - start[1];
+        let len = (dx * dx + dy * dy).sqrt();
+
+        if len == 0.0 { return };
+
+        let nx = -dy / len;
+        let ny =  dx / len;
+
+        let offset_x = nx * (thickness / 2.0);
+        let offset_y = ny * (thickness / 2.0);
+
+        let v0 = [start[0] - offset_x, start[1] - offset_y];
+        let v1 = [end[0]   - offset_x, end[1]   - offset_y];
+        let v2 = [end[0]   + offset_x, end[1]   + offset_y];
+        let v3 = [start[0] + offset_x, start[1] + offset_y];
+        
+        let base_index = self.vertices.len() as u32;
+        let color = PackedColor::from(color.into());
+
+        self.vertices.extend_from_slice(&[
+            Vertex2D { position: v0, color },
+            Vertex2D { position: v1, color },
+            Vertex2D { position: v2, color },
+            Vertex2D { position: v3, color }
+        ]);
+
+        self.indices.extend_from_slice(&[
+            base_index, base_index + 1, base_index + 2,
+            base_index, base_index + 2, base_index + 3
+        ]);
+    }
+
+    pub fn draw_rect(&mut self, start: [f32; 2], end: [f32; 2], color: impl Into<Color>, thickness: f32) {
+        let color = color.into();
+        let x1 = start[0];
+        let y1 = start[1];
+        let x2 = end[0];
+        let y2 = end[1];
+
+        let top_left     = [x1, y1];
+        let top_right    = [x2, y1];
+        let bottom_right = [x2, y2];
+        let bottom_left  = [x1, y2];
+        
+        self.draw_line(top_left, top_right, color, thickness);
+        self.draw_line(top_right, bottom_right, color, thickness);
+        self.draw_line(bottom_right, bottom_left, color, thickness);
+        self.draw_line(bottom_left, top_left, color, thickness);
+    }
+
+
+    pub fn draw_rect_filled(&mut self, start: [f32; 2], end: [f32; 2], color: impl Into<Color>) {
+        let x1 = start[0];
+        let y1 = start[1];
+        let x2 = end[0];
+        let y2 = end[1];
+
+        let v0 = [x1, y1];
+        let v1 = [x2, y1];
+        let v2 = [x2, y2];
+        let v3 = [x1, y2];
+
+        let base_index = self.vertices.len() as u32;
+        let color = PackedColor::from(color.into());
+
+        self.vertices.extend_from_slice(&[
+            Vertex2D { position: v0, color },
+            Vertex2D { position: v1, color },
+            Vertex2D { position: v2, color },
+            Vertex2D { position: v3, color }
+        ]);
+        
+        self.indices.extend_from_slice(&[
+            base_index, base_index + 1, base_index + 2,
+            base_index, base_index + 2, base_index + 3
+        ]);
     }
 }
-unsafe impl Send for Renderer {}
-unsafe impl Sync for Renderer {}
\ No newline at end of file
+unsafe impl<V: VertexFormat> Send for Renderer<V> {}
+unsafe impl<V: VertexFormat> Sync for Renderer<V> {}
\ No newline at end of file
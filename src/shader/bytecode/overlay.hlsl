@@ -0,0 +1,80 @@
+// The .cso blobs next to this file are hand-assembled SM4 bytecode (RDEF creator
+// "edx", no STAT chunk), written to match the entry points below. They are not
+// fxc output. To replace them with compiler output, run:
+//
+//   fxc /T vs_4_0 /E VSMain /Fo overlay_vs.cso overlay.hlsl
+//   fxc /T ps_4_0 /E PSMain /Fo overlay_ps.cso overlay.hlsl
+//   fxc /T vs_4_0 /E VSMain2D /Fo overlay_2d_vs.cso overlay.hlsl
+//   fxc /T ps_4_0 /E PSMainLinear /Fo overlay_linear_ps.cso overlay.hlsl
+//   fxc /T ps_4_0 /E PSMainScrgb /Fo overlay_scrgb_ps.cso overlay.hlsl
+//   fxc /T ps_4_0 /E PSMainHdr10 /Fo overlay_hdr10_ps.cso overlay.hlsl
+
+cbuffer Projection : register(b0) {
+    float2 scale;
+    float2 offset;
+};
+
+cbuffer ColorOutput : register(b0) {
+    float color_scale;
+};
+
+static const float3x3 REC709_TO_REC2020 = {
+    0.6274040, 0.3292820, 0.0433136,
+    0.0690970, 0.9195400, 0.0113612,
+    0.0163916, 0.0880132, 0.8955950
+};
+
+struct VSInput {
+    float3 pos   : POSITION;
+    float4 color : COLOR;
+};
+
+struct VSInput2D {
+    float2 pos   : POSITION;
+    float4 color : COLOR;
+};
+
+struct PSInput {
+    float4 pos   : SV_POSITION;
+    float4 color : COLOR;
+};
+
+PSInput VSMain(VSInput input) {
+    PSInput output;
+    output.pos = float4(input.pos, 1.0);
+    output.color = input.color;
+    return output;
+}
+
+PSInput VSMain2D(VSInput2D input) {
+    PSInput output;
+    output.pos = float4(input.pos * scale + offset, 0.0, 1.0);
+    output.color = input.color;
+    return output;
+}
+
+float4 PSMain(PSInput input) : SV_TARGET {
+    return input.color;
+}
+
+float3 SrgbToLinear(float3 color) {
+    return color <= 0.04045 ? color / 12.92 : pow((color + 0.055) / 1.055, 2.4);
+}
+
+float3 PqEncode(float3 value) {
+    float3 y = pow(max(value, 0.0), 0.1593017578125);
+    return pow((0.8359375 + 18.8515625 * y) / (1.0 + 18.6875 * y), 78.84375);
+}
+
+float4 PSMainLinear(PSInput input) : SV_TARGET {
+    return float4(SrgbToLinear(input.color.rgb), input.color.a);
+}
+
+float4 PSMainScrgb(PSInput input) : SV_TARGET {
+    return float4(SrgbToLinear(input.color.rgb) * color_scale, input.color.a);
+}
+
+float4 PSMainHdr10(PSInput input) : SV_TARGET {
+    float3 rgb = mul(REC709_TO_REC2020, SrgbToLinear(input.color.rgb)) * color_scale;
+    return float4(PqEncode(rgb), input.color.a);
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+use crate::dependencies::{
+    CStr, null_mut, D3D_INCLUDE_TYPE, D3D_INCLUDE_LOCAL, ID3DInclude, ID3DIncludeVtbl, HRESULT, LPCSTR, LPCVOID, UINT, S_OK, E_FAIL
+};
+
+use crate::shader::{ IncludeHandler, IncludeType };
+
+#[repr(C)]
+pub struct D3DInclude {
+    pub include: ID3DInclude,
+    pub handler: *mut IncludeHandler
+}
+impl D3DInclude {
+    const VTBL: ID3DIncludeVtbl = ID3DIncludeVtbl {
+        Open: Self::open,
+        Close: Self::close
+    };
+
+    pub fn build(handler: &mut IncludeHandler) -> Self {
+        Self {
+            include: ID3DInclude { lpVtbl: &Self::VTBL },
+            handler
+        }
+    }
+
+    pub fn as_ptr(&mut self) -> *mut ID3DInclude {
+        self as *mut Self as *mut ID3DInclude
+    }
+
+    unsafe extern "system" fn open(this: *mut ID3DInclude, include_type: D3D_INCLUDE_TYPE, file_name: LPCSTR, parent_data: LPCVOID, data: *mut LPCVOID, bytes: *mut UINT) -> HRESULT {
+        let handler = unsafe { &mut *(*(this as *mut Self)).handler };
+        let name = match unsafe { CStr::from_ptr(file_name) }.to_str() {
+            Ok(name) => name,
+            Err(_) => return E_FAIL
+        };
+        let include_type = if include_type == D3D_INCLUDE_LOCAL { IncludeType::Local } else { IncludeType::System };
+
+        match handler.open(include_type, name, parent_data as *const u8) {
+            Some((source, len)) => {
+                unsafe {
+                    *data = source as LPCVOID;
+                    *bytes = len as UINT;
+                }
+                S_OK
+            },
+            None => {
+                unsafe { *data = null_mut() };
+                E_FAIL
+            }
+        }
+    }
+
+    unsafe extern "system" fn close(this: *mut ID3DInclude, data: LPCVOID) -> HRESULT {
+        let handler = unsafe { &mut *(*(this as *mut Self)).handler };
+
+        if handler.close(data as *const u8) { S_OK } else { E_FAIL }
+    }
+}
\ No newline at end of file
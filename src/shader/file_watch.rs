@@ -0,0 +1,148 @@
+use crate::dependencies::{
+    metadata, Path, PathBuf, SystemTime
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FileStamp {
+    pub path: PathBuf,
+    pub modified: Option<SystemTime>
+}
+impl FileStamp {
+    pub fn build(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let modified = Self::modified(&path);
+
+        Self { path, modified }
+    }
+
+    pub fn modified(path: &Path) -> Option<SystemTime> {
+        metadata(path).ok()?.modified().ok()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FileWatch {
+    pub stamps: Vec<FileStamp>
+}
+impl FileWatch {
+    pub fn build<P: Into<PathBuf>>(paths: impl IntoIterator<Item = P>) -> Self {
+        let mut stamps: Vec<FileStamp> = vec![];
+        for path in paths {
+            let path = path.into();
+            if !stamps.iter().any(|stamp| stamp.path == path) {
+                stamps.push(FileStamp::build(path));
+            }
+        }
+
+        Self { stamps }
+    }
+
+    pub fn retarget<P: Into<PathBuf>>(&self, paths: impl IntoIterator<Item = P>) -> Self {
+        let mut stamps: Vec<FileStamp> = vec![];
+        for path in paths {
+            let path = path.into();
+            if stamps.iter().any(|stamp| stamp.path == path) {
+                continue;
+            }
+
+            match self.stamps.iter().find(|stamp| stamp.path == path) {
+                Some(stamp) => stamps.push(stamp.clone()),
+                None => stamps.push(FileStamp::build(path))
+            }
+        }
+
+        Self { stamps }
+    }
+
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.stamps.iter().map(|stamp| stamp.path.as_path())
+    }
+
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for stamp in self.stamps.iter_mut() {
+            let modified = FileStamp::modified(&stamp.path);
+            if modified != stamp.modified {
+                stamp.modified = modified;
+                changed = true;
+            }
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependencies::{ write, create_dir_all, Duration, File };
+
+    fn directory(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("edx-file-watch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        create_dir_all(&root).unwrap();
+
+        root
+    }
+
+    fn touch(path: &Path, seconds: u64) {
+        File::options().write(true).open(path).unwrap().set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(seconds)).unwrap();
+    }
+
+    #[test]
+    fn build_deduplicates_paths() {
+        let root = directory("dedup");
+        let watch = FileWatch::build([root.join("a.hlsl"), root.join("b.hlsl"), root.join("a.hlsl")]);
+
+        assert_eq!(watch.paths().collect::<Vec<_>>(), vec![root.join("a.hlsl").as_path(), root.join("b.hlsl").as_path()]);
+        assert!(watch.stamps.iter().all(|stamp| stamp.modified.is_none()));
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn detects_modifications_once() {
+        let root = directory("changed");
+        let path = root.join("shader.hlsl");
+        write(&path, b"a").unwrap();
+        touch(&path, 1_000);
+
+        let mut watch = FileWatch::build([&path]);
+        assert!(!watch.changed());
+
+        touch(&path, 2_000);
+        assert!(watch.changed());
+        assert!(!watch.changed());
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(watch.changed());
+        assert!(!watch.changed());
+
+        write(&path, b"b").unwrap();
+        assert!(watch.changed());
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn retarget_keeps_existing_stamps() {
+        let root = directory("retarget");
+        let (source, include) = (root.join("shader.hlsl"), root.join("common.hlsl"));
+        write(&source, b"a").unwrap();
+        write(&include, b"b").unwrap();
+        touch(&source, 1_000);
+
+        let watch = FileWatch::build([&source]);
+        touch(&source, 2_000);
+
+        let mut watch = watch.retarget([&source, &include, &source]);
+        assert_eq!(watch.stamps.len(), 2);
+        assert_eq!(watch.stamps[0].modified, Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000)));
+        assert!(watch.changed());
+
+        let watch = watch.retarget([&include]);
+        assert_eq!(watch.paths().collect::<Vec<_>>(), vec![include.as_path()]);
+
+        let _ = std::fs::remove_dir_all(&root);
+    }
+}
\ No newline at end of file
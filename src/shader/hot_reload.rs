@@ -0,0 +1,188 @@
+#[cfg(all(windows, feature = "compiler"))]
+use crate::dependencies::{
+    read, Arc, Mutex, AtomicBool, Ordering, channel, Sender, Receiver, spawn, sleep, Duration, PathBuf
+};
+
+#[cfg(all(windows, feature = "compiler"))]
+use crate::{ ShaderBuilder, ShaderStage, IncludeResolver, FileWatch };
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ShaderReload {
+    Compiled { vs_bytecode: Vec<u8>, ps_bytecode: Vec<u8> },
+    Failed { diagnostics: String }
+}
+impl ShaderReload {
+    pub fn latest(reloads: impl IntoIterator<Item = Self>) -> Option<Self> {
+        reloads.into_iter().fold(None, |latest, reload| match (&latest, &reload) {
+            (Some(Self::Compiled { .. }), Self::Failed { .. }) => latest,
+            _ => Some(reload)
+        })
+    }
+}
+
+#[cfg(all(windows, feature = "compiler"))]
+struct WatcherState {
+    stop: Arc<AtomicBool>,
+    receiver: Mutex<Receiver<ShaderReload>>,
+    last_error: Arc<Mutex<Option<String>>>,
+    compiled: Mutex<Option<(Vec<u8>, Vec<u8>)>>
+}
+#[cfg(all(windows, feature = "compiler"))]
+impl Drop for WatcherState {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+#[cfg(all(windows, feature = "compiler"))]
+struct WatcherTask {
+    vs_path: PathBuf,
+    ps_path: PathBuf,
+    vertex: ShaderStage,
+    pixel: ShaderStage,
+    include: Option<Arc<dyn IncludeResolver>>,
+    interval: Duration,
+    stop: Arc<AtomicBool>,
+    sender: Sender<ShaderReload>,
+    last_error: Arc<Mutex<Option<String>>>
+}
+#[cfg(all(windows, feature = "compiler"))]
+impl WatcherTask {
+    fn run(self) {
+        let mut watch = self.compile(&FileWatch::default());
+
+        while !self.stop.load(Ordering::Relaxed) {
+            sleep(self.interval);
+            if watch.changed() {
+                watch = self.compile(&watch);
+            }
+        }
+    }
+
+    fn compile(&self, previous: &FileWatch) -> FileWatch {
+        let mut paths = vec![self.vs_path.clone(), self.ps_path.clone()];
+        let watch = FileWatch::build(paths.iter().cloned().chain(previous.paths().map(|path| path.to_path_buf())));
+        let reload = match (read(&self.vs_path), read(&self.ps_path)) {
+            (Ok(vs_source), Ok(ps_source)) => {
+                let vs_output = self.vertex.compile_output(&vs_source, self.include.as_ref());
+                let ps_output = self.pixel.compile_output(&ps_source, self.include.as_ref());
+                paths.extend(vs_output.includes.iter().chain(ps_output.includes.iter()).map(|file| PathBuf::from(&file.path)));
+
+                match (vs_output.bytecode, ps_output.bytecode) {
+                    (Some(vs_bytecode), Some(ps_bytecode)) => ShaderReload::Compiled { vs_bytecode, ps_bytecode },
+                    _ => ShaderReload::Failed {
+                        diagnostics: [vs_output.diagnostics, ps_output.diagnostics]
+                            .iter()
+                            .filter(|diagnostics| !diagnostics.is_empty())
+                            .cloned()
+                            .collect::<Vec<String>>()
+                            .join("\n")
+                    }
+                }
+            },
+            (Err(error), _) => ShaderReload::Failed { diagnostics: format!("{}: {}", self.vs_path.display(), error) },
+            (_, Err(error)) => ShaderReload::Failed { diagnostics: format!("{}: {}", self.ps_path.display(), error) }
+        };
+
+        if let Ok(mut last_error) = self.last_error.lock() {
+            *last_error = match &reload {
+                ShaderReload::Compiled { .. } => None,
+                ShaderReload::Failed { diagnostics } => Some(diagnostics.clone())
+            };
+        }
+        if self.sender.send(reload).is_err() {
+            self.stop.store(true, Ordering::Relaxed);
+        }
+
+        watch.retarget(paths)
+    }
+}
+
+#[cfg(all(windows, feature = "compiler"))]
+#[derive(Clone)]
+pub struct ShaderWatcher {
+    state: Arc<WatcherState>
+}
+#[cfg(all(windows, feature = "compiler"))]
+impl ShaderWatcher {
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(250);
+
+    pub fn create(vs_path: impl Into<PathBuf>, ps_path: impl Into<PathBuf>, builder: &ShaderBuilder) -> Self {
+        Self::create_with_interval(vs_path, ps_path, builder, Self::DEFAULT_INTERVAL)
+    }
+
+    pub fn create_with_interval(vs_path: impl Into<PathBuf>, ps_path: impl Into<PathBuf>, builder: &ShaderBuilder, interval: Duration) -> Self {
+        let (sender, receiver) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let last_error = Arc::new(Mutex::new(None));
+
+        let task = WatcherTask {
+            vs_path: vs_path.into(),
+            ps_path: ps_path.into(),
+            vertex: builder.vertex.clone(),
+            pixel: builder.pixel.clone(),
+            include: builder.include.clone(),
+            interval,
+            stop: stop.clone(),
+            sender,
+            last_error: last_error.clone()
+        };
+        spawn(move || task.run());
+
+        let state = Arc::new(WatcherState { stop, receiver: Mutex::new(receiver), last_error, compiled: Mutex::new(None) });
+
+        Self { state }
+    }
+
+    pub fn poll(&self) -> Option<ShaderReload> {
+        let receiver = self.state.receiver.lock().ok()?;
+        let reload = ShaderReload::latest(receiver.try_iter());
+
+        if let Some(ShaderReload::Compiled { vs_bytecode, ps_bytecode }) = reload.as_ref() && let Ok(mut compiled) = self.state.compiled.lock() {
+            *compiled = Some((vs_bytecode.clone(), ps_bytecode.clone()));
+        }
+
+        reload
+    }
+
+    pub fn last_compiled(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        self.state.compiled.lock().ok()?.clone()
+    }
+
+    pub fn last_error(&self) -> Option<String> {
+        self.state.last_error.lock().ok()?.clone()
+    }
+
+    pub fn report_error(&self, diagnostics: &str) {
+        if let Ok(mut last_error) = self.state.last_error.lock() {
+            *last_error = Some(diagnostics.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compiled(byte: u8) -> ShaderReload {
+        ShaderReload::Compiled { vs_bytecode: vec![byte], ps_bytecode: vec![byte, byte] }
+    }
+
+    fn failed(diagnostics: &str) -> ShaderReload {
+        ShaderReload::Failed { diagnostics: diagnostics.to_string() }
+    }
+
+    #[test]
+    fn latest_prefers_the_newest_reload() {
+        assert_eq!(ShaderReload::latest(vec![]), None);
+        assert_eq!(ShaderReload::latest(vec![compiled(1), compiled(2)]), Some(compiled(2)));
+        assert_eq!(ShaderReload::latest(vec![failed("a"), failed("b")]), Some(failed("b")));
+        assert_eq!(ShaderReload::latest(vec![failed("a"), compiled(1)]), Some(compiled(1)));
+    }
+
+    #[test]
+    fn latest_keeps_compiled_over_later_failures() {
+        assert_eq!(ShaderReload::latest(vec![compiled(1), failed("a")]), Some(compiled(1)));
+        assert_eq!(ShaderReload::latest(vec![compiled(1), failed("a"), compiled(2), failed("b")]), Some(compiled(2)));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,277 @@
+use crate::dependencies::{
+    read, Arc, HashMap, Path, PathBuf
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IncludeType {
+    Local,
+    System
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IncludeFile {
+    pub path: String,
+    pub source: Vec<u8>
+}
+
+pub trait IncludeResolver: Send + Sync {
+    fn resolve(&self, include_type: IncludeType, name: &str, parent: Option<&str>) -> Option<IncludeFile>;
+}
+
+#[derive(Clone, Debug)]
+pub struct DirectoryInclude {
+    pub root: PathBuf
+}
+impl DirectoryInclude {
+    pub fn build(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+impl IncludeResolver for DirectoryInclude {
+    fn resolve(&self, include_type: IncludeType, name: &str, parent: Option<&str>) -> Option<IncludeFile> {
+        let parent_dir = match (include_type, parent) {
+            (IncludeType::Local, Some(parent)) => Path::new(parent)
+                .strip_prefix(&self.root)
+                .ok()
+                .map(|parent| parent.to_string_lossy().into_owned())
+                .map(|parent| match parent.rfind(['/', '\\']) {
+                    Some(index) => parent[..index].to_string(),
+                    None => String::new()
+                }),
+            _ => None
+        };
+        let relative = match parent_dir {
+            Some(dir) if !is_absolute(name) => confine_path(&format!("{}/{}", dir, name)),
+            _ => None
+        };
+
+        relative
+            .into_iter()
+            .chain(confine_path(name))
+            .find_map(|path| {
+                let path = self.root.join(path);
+                let source = read(&path).ok()?;
+                Some(IncludeFile { path: path.to_string_lossy().into_owned(), source })
+            })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryInclude {
+    pub sources: HashMap<String, Vec<u8>>
+}
+impl MemoryInclude {
+    pub fn build() -> Self {
+        Self { sources: HashMap::new() }
+    }
+
+    pub fn insert(mut self, name: &str, source: &[u8]) -> Self {
+        self.sources.insert(normalize_path(name), source.to_vec());
+        self
+    }
+}
+impl IncludeResolver for MemoryInclude {
+    fn resolve(&self, include_type: IncludeType, name: &str, parent: Option<&str>) -> Option<IncludeFile> {
+        let relative = match (include_type, parent) {
+            (IncludeType::Local, Some(parent)) => Some(match parent.rfind('/') {
+                Some(index) => normalize_path(&format!("{}/{}", &parent[..index], name)),
+                None => normalize_path(name)
+            }),
+            _ => None
+        };
+
+        relative
+            .into_iter()
+            .chain(Some(normalize_path(name)))
+            .find_map(|path| {
+                let source = self.sources.get(&path)?.clone();
+                Some(IncludeFile { path, source })
+            })
+    }
+}
+
+pub struct CallbackInclude<F> {
+    pub callback: F
+}
+impl<F> CallbackInclude<F>
+where
+    F: Fn(IncludeType, &str, Option<&str>) -> Option<Vec<u8>> + Send + Sync
+{
+    pub fn build(callback: F) -> Self {
+        Self { callback }
+    }
+}
+impl<F> IncludeResolver for CallbackInclude<F>
+where
+    F: Fn(IncludeType, &str, Option<&str>) -> Option<Vec<u8>> + Send + Sync
+{
+    fn resolve(&self, include_type: IncludeType, name: &str, parent: Option<&str>) -> Option<IncludeFile> {
+        let source = (self.callback)(include_type, name, parent)?;
+
+        Some(IncludeFile { path: name.to_string(), source })
+    }
+}
+
+pub struct IncludeHandler {
+    pub resolver: Arc<dyn IncludeResolver>,
+    pub resolved: Vec<IncludeFile>
+}
+impl IncludeHandler {
+    pub fn build(resolver: Arc<dyn IncludeResolver>) -> Self {
+        Self { resolver, resolved: vec![] }
+    }
+
+    pub fn open(&mut self, include_type: IncludeType, name: &str, parent_data: *const u8) -> Option<(*const u8, usize)> {
+        let parent = self.resolved
+            .iter()
+            .find(|file| file.source.as_ptr() == parent_data)
+            .map(|file| file.path.clone());
+
+        let file = self.resolver.resolve(include_type, name, parent.as_deref())?;
+        let data = (file.source.as_ptr(), file.source.len());
+        self.resolved.push(file);
+
+        Some(data)
+    }
+
+    pub fn close(&mut self, data: *const u8) -> bool {
+        self.resolved.iter().any(|file| file.source.as_ptr() == data)
+    }
+}
+
+fn is_absolute(path: &str) -> bool {
+    path.starts_with(['/', '\\']) || path.split(['/', '\\']).next().is_some_and(|part| part.contains(':'))
+}
+
+fn confine_path(path: &str) -> Option<String> {
+    if is_absolute(path) {
+        return None;
+    }
+
+    let mut parts: Vec<&str> = vec![];
+    for part in path.split(['/', '\\']) {
+        match part {
+            "" | "." => {},
+            ".." => { parts.pop()?; },
+            part => parts.push(part)
+        }
+    }
+
+    Some(parts.join("/"))
+}
+
+fn normalize_path(path: &str) -> String {
+    let mut parts: Vec<&str> = vec![];
+
+    for part in path.split(['/', '\\']) {
+        match part {
+            "" | "." => {},
+            ".." => { parts.pop(); },
+            part => parts.push(part)
+        }
+    }
+
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dependencies::{ write, create_dir_all };
+
+    fn directory(name: &str) -> PathBuf {
+        let base = std::env::temp_dir().join(format!("edx-include-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+
+        let root = base.join("root");
+        create_dir_all(root.join("shaders/common")).unwrap();
+        write(root.join("shaders/main.hlsl"), b"main").unwrap();
+        write(root.join("shaders/lighting.hlsl"), b"lighting").unwrap();
+        write(root.join("shaders/common/util.hlsl"), b"util").unwrap();
+        write(root.join("util.hlsl"), b"root util").unwrap();
+        write(base.join("secret.hlsl"), b"secret").unwrap();
+
+        root
+    }
+
+    fn source(file: Option<IncludeFile>) -> Option<Vec<u8>> {
+        file.map(|file| file.source)
+    }
+
+    #[test]
+    fn directory_resolves_relative_to_parent() {
+        let root = directory("parent");
+        let include = DirectoryInclude::build(&root);
+
+        let util = include.resolve(IncludeType::Local, "shaders/common/util.hlsl", None).unwrap();
+        assert_eq!(util.source, b"util");
+        assert_eq!(source(include.resolve(IncludeType::Local, "../lighting.hlsl", Some(&util.path))), Some(b"lighting".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::Local, "./../main.hlsl", Some(&util.path))), Some(b"main".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::Local, "util.hlsl", Some(&util.path))), Some(b"util".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::System, "util.hlsl", Some(&util.path))), Some(b"root util".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::Local, "lighting.hlsl", Some(&util.path))), None);
+        assert_eq!(source(include.resolve(IncludeType::Local, "shaders/./common/../main.hlsl", None)), Some(b"main".to_vec()));
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn directory_rejects_escapes() {
+        let root = directory("escape");
+        let include = DirectoryInclude::build(&root);
+        let util = include.resolve(IncludeType::Local, "shaders/common/util.hlsl", None).unwrap();
+        let secret = root.parent().unwrap().join("secret.hlsl");
+
+        assert!(secret.exists());
+        assert!(include.resolve(IncludeType::Local, "../secret.hlsl", None).is_none());
+        assert!(include.resolve(IncludeType::Local, "shaders/../../secret.hlsl", None).is_none());
+        assert!(include.resolve(IncludeType::Local, "../../../secret.hlsl", Some(&util.path)).is_none());
+        assert!(include.resolve(IncludeType::Local, "..\\..\\..\\secret.hlsl", Some(&util.path)).is_none());
+        assert!(include.resolve(IncludeType::Local, &secret.to_string_lossy(), None).is_none());
+        assert!(include.resolve(IncludeType::Local, "C:/secret.hlsl", None).is_none());
+
+        let _ = std::fs::remove_dir_all(root.parent().unwrap());
+    }
+
+    #[test]
+    fn memory_resolves_relative_to_parent() {
+        let include = MemoryInclude::build()
+            .insert("shaders/common/util.hlsl", b"util")
+            .insert("shaders\\lighting.hlsl", b"lighting")
+            .insert("util.hlsl", b"root util");
+
+        let util = include.resolve(IncludeType::Local, "./shaders/common/util.hlsl", None).unwrap();
+        assert_eq!(util.path, "shaders/common/util.hlsl");
+        assert_eq!(source(include.resolve(IncludeType::Local, "../lighting.hlsl", Some(&util.path))), Some(b"lighting".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::Local, "util.hlsl", Some(&util.path))), Some(b"util".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::System, "util.hlsl", Some(&util.path))), Some(b"root util".to_vec()));
+        assert_eq!(source(include.resolve(IncludeType::Local, "missing.hlsl", None)), None);
+    }
+
+    #[test]
+    fn callback_receives_the_request() {
+        let include = CallbackInclude::build(|include_type, name: &str, parent: Option<&str>| match (include_type, name, parent) {
+            (IncludeType::System, "common.hlsl", Some("main.hlsl")) => Some(b"common".to_vec()),
+            _ => None
+        });
+
+        let file = include.resolve(IncludeType::System, "common.hlsl", Some("main.hlsl")).unwrap();
+        assert_eq!(file, IncludeFile { path: "common.hlsl".to_string(), source: b"common".to_vec() });
+        assert!(include.resolve(IncludeType::Local, "common.hlsl", Some("main.hlsl")).is_none());
+        assert!(include.resolve(IncludeType::System, "common.hlsl", None).is_none());
+    }
+
+    #[test]
+    fn handler_tracks_parents() {
+        let include = MemoryInclude::build()
+            .insert("shaders/main.hlsl", b"main")
+            .insert("shaders/common.hlsl", b"common");
+        let mut handler = IncludeHandler::build(Arc::new(include));
+
+        let (main, _) = handler.open(IncludeType::Local, "shaders/main.hlsl", std::ptr::null()).unwrap();
+        let (common, length) = handler.open(IncludeType::Local, "common.hlsl", main).unwrap();
+        assert_eq!(length, 6);
+        assert!(handler.close(common));
+        assert!(!handler.close(std::ptr::null()));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,170 @@
+#[cfg(all(windows, feature = "compiler"))]
+mod shader_builder;
+#[cfg(all(windows, feature = "compiler"))]
+pub use shader_builder::{ ShaderBuilder, ShaderStage, OptimizationLevel, CompileOutput };
+
+mod shader_cache;
+pub use shader_cache::{ ShaderCache, CacheKey, CacheEntry, scan_includes };
+
+mod file_watch;
+pub use file_watch::{ FileWatch, FileStamp };
+
+mod hot_reload;
+pub use hot_reload::ShaderReload;
+#[cfg(all(windows, feature = "compiler"))]
+pub use hot_reload::ShaderWatcher;
+
+mod include;
+pub use include::{ IncludeType, IncludeFile, IncludeResolver, IncludeHandler, DirectoryInclude, MemoryInclude, CallbackInclude };
+
+#[cfg(all(windows, feature = "compiler"))]
+mod d3d_include;
+#[cfg(all(windows, feature = "compiler"))]
+pub use d3d_include::D3DInclude;
+
+#[cfg(windows)]
+use crate::dependencies::{
+    null_mut, PhantomData, SUCCEEDED, ID3D11Device, ID3D11DeviceContext, ID3D11VertexShader, ID3D11PixelShader, ID3D11InputLayout
+};
+
+#[cfg(windows)]
+use crate::{ ComPtr, ShaderReflection, Vertex, Vertex2D, VertexFormat, ColorEncoding };
+
+#[cfg(windows)]
+#[derive(Clone)]
+pub struct Shader<V: VertexFormat = Vertex2D> {
+    pub vertex: ComPtr<ID3D11VertexShader>,
+    pub pixel: ComPtr<ID3D11PixelShader>,
+    pub input_layout: ComPtr<ID3D11InputLayout>,
+    pub vs_bytecode: Vec<u8>,
+    pub vertex_reflection: ShaderReflection,
+    pub pixel_reflection: ShaderReflection,
+    pub marker: PhantomData<V>
+}
+#[cfg(windows)]
+impl<V: VertexFormat> Shader<V> {
+    pub const OVERLAY_VS: &'static [u8] = include_bytes!("bytecode/overlay_vs.cso");
+    pub const OVERLAY_PS: &'static [u8] = include_bytes!("bytecode/overlay_ps.cso");
+    pub const OVERLAY_2D_VS: &'static [u8] = include_bytes!("bytecode/overlay_2d_vs.cso");
+    pub const OVERLAY_LINEAR_PS: &'static [u8] = include_bytes!("bytecode/overlay_linear_ps.cso");
+    pub const OVERLAY_SCRGB_PS: &'static [u8] = include_bytes!("bytecode/overlay_scrgb_ps.cso");
+    pub const OVERLAY_HDR10_PS: &'static [u8] = include_bytes!("bytecode/overlay_hdr10_ps.cso");
+
+    pub fn overlay_pixel(encoding: ColorEncoding) -> &'static [u8] {
+        match encoding {
+            ColorEncoding::Srgb => Self::OVERLAY_PS,
+            ColorEncoding::Linear => Self::OVERLAY_LINEAR_PS,
+            ColorEncoding::Scrgb => Self::OVERLAY_SCRGB_PS,
+            ColorEncoding::Hdr10 => Self::OVERLAY_HDR10_PS
+        }
+    }
+
+    #[cfg(feature = "compiler")]
+    pub fn build(vs_source: &[u8], ps_source: &[u8], device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        ShaderBuilder::build(vs_source, ps_source).create(device)
+    }
+
+    pub fn from_bytecode(vs_bytecode: &[u8], ps_bytecode: &[u8], device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        let vertex = match Self::create_vertex(device, vs_bytecode) {
+            Some(vertex) => vertex,
+            None => return None
+        };
+        let pixel = match Self::create_pixel(device, ps_bytecode) {
+            Some(pixel) => pixel,
+            None => return None
+        };
+        let vertex_reflection = match ShaderReflection::parse(vs_bytecode) {
+            Some(vertex_reflection) => vertex_reflection,
+            None => return None
+        };
+        let pixel_reflection = match ShaderReflection::parse(ps_bytecode) {
+            Some(pixel_reflection) => pixel_reflection,
+            None => return None
+        };
+        if !V::matches(&vertex_reflection) {
+            return None;
+        }
+        let input_layout = match Self::create_input_layout(device, vs_bytecode) {
+            Some(input_layout) => input_layout,
+            None => return None
+        };
+        let vs_bytecode = vs_bytecode.to_vec();
+
+        let marker = PhantomData;
+
+        Some(Self { vertex, pixel, input_layout, vs_bytecode, vertex_reflection, pixel_reflection, marker })
+    }
+
+    pub fn setup(&self, context: &ComPtr<ID3D11DeviceContext>) {
+        unsafe {
+            context.VSSetShader(self.vertex.as_ptr(), null_mut(), 0);
+            context.PSSetShader(self.pixel.as_ptr(), null_mut(), 0);
+            context.IASetInputLayout(self.input_layout.as_ptr());
+        }
+    }
+
+    fn create_vertex(device: &ComPtr<ID3D11Device>, bytecode: &[u8]) -> Option<ComPtr<ID3D11VertexShader>> {
+        let mut shader: *mut ID3D11VertexShader = null_mut();
+        let hr = unsafe { device.CreateVertexShader(
+            bytecode.as_ptr() as *const _,
+            bytecode.len(),
+            null_mut(),
+            &mut shader,
+        ) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(shader) } };
+
+        None
+    }
+
+    fn create_pixel(device: &ComPtr<ID3D11Device>, bytecode: &[u8]) -> Option<ComPtr<ID3D11PixelShader>> {
+        let mut shader: *mut ID3D11PixelShader = null_mut();
+        let hr = unsafe { device.CreatePixelShader(
+            bytecode.as_ptr() as *const _,
+            bytecode.len(),
+            null_mut(),
+            &mut shader,
+        ) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(shader) } };
+
+        None
+    }
+
+    fn create_input_layout(device: &ComPtr<ID3D11Device>, bytecode: &[u8]) -> Option<ComPtr<ID3D11InputLayout>> {
+        let (_names, layout_desc) = V::input_layout()?;
+
+        let mut input_layout: *mut ID3D11InputLayout = null_mut();
+        let hr = unsafe { device.CreateInputLayout(
+            layout_desc.as_ptr(),
+            layout_desc.len() as u32,
+            bytecode.as_ptr() as *const _,
+            bytecode.len(),
+            &mut input_layout,
+        ) };
+
+        if SUCCEEDED(hr) { return unsafe { ComPtr::from_raw(input_layout) } };
+
+        None
+    }
+}
+#[cfg(windows)]
+impl Shader<Vertex> {
+    pub fn overlay(device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        Self::from_bytecode(Self::OVERLAY_VS, Self::OVERLAY_PS, device)
+    }
+}
+#[cfg(windows)]
+impl Shader<Vertex2D> {
+    pub fn overlay_2d(device: &ComPtr<ID3D11Device>) -> Option<Self> {
+        Self::overlay_2d_encoded(device, ColorEncoding::Srgb)
+    }
+
+    pub fn overlay_2d_encoded(device: &ComPtr<ID3D11Device>, encoding: ColorEncoding) -> Option<Self> {
+        Self::from_bytecode(Self::OVERLAY_2D_VS, Self::overlay_pixel(encoding), device)
+    }
+}
+#[cfg(windows)]
+unsafe impl<V: VertexFormat> Send for Shader<V> {}
+#[cfg(windows)]
+unsafe impl<V: VertexFormat> Sync for Shader<V> {}
\ No newline at end of file
@@ -0,0 +1,274 @@
+use crate::dependencies::{
+    Arc, CString, null, null_mut, from_raw_parts, SUCCEEDED, D3DCompile, ID3DBlob, D3D_SHADER_MACRO, D3DCOMPILE_DEBUG, D3DCOMPILE_SKIP_OPTIMIZATION, D3DCOMPILE_OPTIMIZATION_LEVEL0, D3DCOMPILE_OPTIMIZATION_LEVEL1, D3DCOMPILE_OPTIMIZATION_LEVEL2, D3DCOMPILE_OPTIMIZATION_LEVEL3, ID3D11Device, UINT
+};
+
+use crate::{ ComPtr, Shader, IncludeResolver, IncludeHandler, IncludeFile, D3DInclude, CacheKey, ShaderCache, VertexFormat };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OptimizationLevel {
+    Skip,
+    Level0,
+    Level1,
+    Level2,
+    Level3
+}
+impl OptimizationLevel {
+    pub fn flags(&self) -> UINT {
+        match self {
+            Self::Skip => D3DCOMPILE_SKIP_OPTIMIZATION,
+            Self::Level0 => D3DCOMPILE_OPTIMIZATION_LEVEL0,
+            Self::Level1 => D3DCOMPILE_OPTIMIZATION_LEVEL1,
+            Self::Level2 => D3DCOMPILE_OPTIMIZATION_LEVEL2,
+            Self::Level3 => D3DCOMPILE_OPTIMIZATION_LEVEL3
+        }
+    }
+}
+impl Default for OptimizationLevel {
+    fn default() -> Self {
+        if cfg!(debug_assertions) { Self::Skip } else { Self::Level3 }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ShaderStage {
+    pub entry_point: String,
+    pub profile: String,
+    pub defines: Vec<(String, String)>,
+    pub optimization: OptimizationLevel,
+    pub debug: bool
+}
+impl ShaderStage {
+    pub fn vertex() -> Self {
+        Self::build("VSMain", "vs_5_0")
+    }
+
+    pub fn pixel() -> Self {
+        Self::build("PSMain", "ps_5_0")
+    }
+
+    pub fn build(entry_point: &str, profile: &str) -> Self {
+        Self {
+            entry_point: entry_point.to_string(),
+            profile: profile.to_string(),
+            defines: vec![],
+            optimization: OptimizationLevel::default(),
+            debug: cfg!(debug_assertions)
+        }
+    }
+
+    pub fn flags(&self) -> UINT {
+        let mut flags = self.optimization.flags();
+        if self.debug { flags |= D3DCOMPILE_DEBUG };
+
+        flags
+    }
+
+    pub fn macros(&self) -> Option<(Vec<CString>, Vec<D3D_SHADER_MACRO>)> {
+        let mut strings = Vec::with_capacity(self.defines.len() * 2);
+        for (name, value) in self.defines.iter() {
+            strings.push(CString::new(name.as_str()).ok()?);
+            strings.push(CString::new(value.as_str()).ok()?);
+        }
+
+        let mut macros: Vec<D3D_SHADER_MACRO> = strings
+            .chunks(2)
+            .map(|define| D3D_SHADER_MACRO { Name: define[0].as_ptr(), Definition: define[1].as_ptr() })
+            .collect();
+        macros.push(D3D_SHADER_MACRO { Name: null(), Definition: null() });
+
+        Some((strings, macros))
+    }
+
+    pub fn compile(&self, source: &[u8], include: Option<&Arc<dyn IncludeResolver>>) -> Option<Vec<u8>> {
+        self.compile_output(source, include).bytecode
+    }
+
+    pub fn cache_key(&self, source: &[u8], include: Option<&Arc<dyn IncludeResolver>>) -> CacheKey {
+        CacheKey::compute(
+            source,
+            include.map(|include| include.as_ref()),
+            &self.defines,
+            &self.entry_point,
+            &self.profile,
+            self.flags()
+        )
+    }
+
+    pub fn compile_cached(&self, source: &[u8], include: Option<&Arc<dyn IncludeResolver>>, cache: Option<&ShaderCache>) -> Option<Vec<u8>> {
+        let cache = match cache {
+            Some(cache) => cache,
+            None => return self.compile(source, include)
+        };
+        let key = self.cache_key(source, include);
+
+        if let Some(bytecode) = cache.load(key) {
+            return Some(bytecode);
+        }
+
+        let bytecode = self.compile(source, include)?;
+        cache.store(key, &bytecode);
+
+        Some(bytecode)
+    }
+
+    pub fn compile_output(&self, source: &[u8], include: Option<&Arc<dyn IncludeResolver>>) -> CompileOutput {
+        let mut blob: *mut ID3DBlob = null_mut();
+        let mut error_blob: *mut ID3DBlob = null_mut();
+        let (entry_point_cstr, target_cstr, (_defines, macros)) = match (
+            CString::new(self.entry_point.as_str()),
+            CString::new(self.profile.as_str()),
+            self.macros()
+        ) {
+            (Ok(entry_point_cstr), Ok(target_cstr), Some(macros)) => (entry_point_cstr, target_cstr, macros),
+            _ => return CompileOutput::failed("invalid entry point, profile or define")
+        };
+        let mut handler = include.map(|resolver| IncludeHandler::build(resolver.clone()));
+        let mut d3d_include = handler.as_mut().map(D3DInclude::build);
+
+        let hr = unsafe { D3DCompile(
+            source.as_ptr() as *const _,
+            source.len() as _,
+            null_mut(),
+            macros.as_ptr(),
+            d3d_include.as_mut().map_or(null_mut(), D3DInclude::as_ptr),
+            entry_point_cstr.as_ptr(),
+            target_cstr.as_ptr(),
+            self.flags(),
+            0,
+            &mut blob,
+            &mut error_blob
+        ) };
+
+        let blob = unsafe { ComPtr::from_raw(blob) };
+        let error_blob = unsafe { ComPtr::from_raw(error_blob) };
+        let diagnostics = error_blob
+            .as_ref()
+            .map(|error_blob| String::from_utf8_lossy(Self::bytes(error_blob)).trim_end_matches('\0').to_string())
+            .unwrap_or_default();
+        let includes = handler.map(|handler| handler.resolved).unwrap_or_default();
+
+        let bytecode = match blob {
+            Some(blob) if SUCCEEDED(hr) => Some(Self::bytes(&blob).to_vec()),
+            _ => None
+        };
+
+        CompileOutput { bytecode, diagnostics, includes }
+    }
+
+    fn bytes(blob: &ComPtr<ID3DBlob>) -> &[u8] {
+        unsafe { from_raw_parts(blob.GetBufferPointer() as *const u8, blob.GetBufferSize()) }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CompileOutput {
+    pub bytecode: Option<Vec<u8>>,
+    pub diagnostics: String,
+    pub includes: Vec<IncludeFile>
+}
+impl CompileOutput {
+    fn failed(diagnostics: &str) -> Self {
+        Self { bytecode: None, diagnostics: diagnostics.to_string(), includes: vec![] }
+    }
+}
+
+#[derive(Clone)]
+pub struct ShaderBuilder<'a> {
+    pub vs_source: &'a [u8],
+    pub ps_source: &'a [u8],
+    pub vertex: ShaderStage,
+    pub pixel: ShaderStage,
+    pub include: Option<Arc<dyn IncludeResolver>>,
+    pub cache: Option<ShaderCache>
+}
+impl<'a> ShaderBuilder<'a> {
+    pub fn build(vs_source: &'a [u8], ps_source: &'a [u8]) -> Self {
+        Self {
+            vs_source,
+            ps_source,
+            vertex: ShaderStage::vertex(),
+            pixel: ShaderStage::pixel(),
+            include: None,
+            cache: None
+        }
+    }
+
+    pub fn include(mut self, resolver: impl IncludeResolver + 'static) -> Self {
+        self.include = Some(Arc::new(resolver));
+        self
+    }
+
+    pub fn cache(mut self, cache: ShaderCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn vertex_stage(mut self, stage: ShaderStage) -> Self {
+        self.vertex = stage;
+        self
+    }
+
+    pub fn pixel_stage(mut self, stage: ShaderStage) -> Self {
+        self.pixel = stage;
+        self
+    }
+
+    pub fn vertex_entry_point(mut self, entry_point: &str) -> Self {
+        self.vertex.entry_point = entry_point.to_string();
+        self
+    }
+
+    pub fn pixel_entry_point(mut self, entry_point: &str) -> Self {
+        self.pixel.entry_point = entry_point.to_string();
+        self
+    }
+
+    pub fn vertex_profile(mut self, profile: &str) -> Self {
+        self.vertex.profile = profile.to_string();
+        self
+    }
+
+    pub fn pixel_profile(mut self, profile: &str) -> Self {
+        self.pixel.profile = profile.to_string();
+        self
+    }
+
+    pub fn define(self, name: &str, value: &str) -> Self {
+        self.vertex_define(name, value).pixel_define(name, value)
+    }
+
+    pub fn vertex_define(mut self, name: &str, value: &str) -> Self {
+        self.vertex.defines.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn pixel_define(mut self, name: &str, value: &str) -> Self {
+        self.pixel.defines.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn optimization(mut self, optimization: OptimizationLevel) -> Self {
+        self.vertex.optimization = optimization;
+        self.pixel.optimization = optimization;
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.vertex.debug = debug;
+        self.pixel.debug = debug;
+        self
+    }
+
+    pub fn compile(&self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let vs_bytecode = self.vertex.compile_cached(self.vs_source, self.include.as_ref(), self.cache.as_ref())?;
+        let ps_bytecode = self.pixel.compile_cached(self.ps_source, self.include.as_ref(), self.cache.as_ref())?;
+
+        Some((vs_bytecode, ps_bytecode))
+    }
+
+    pub fn create<V: VertexFormat>(&self, device: &ComPtr<ID3D11Device>) -> Option<Shader<V>> {
+        let (vs_bytecode, ps_bytecode) = self.compile()?;
+
+        Shader::from_bytecode(&vs_bytecode, &ps_bytecode, device)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,356 @@
+use crate::dependencies::{
+    read, write, rename, remove_file, read_dir, create_dir_all, File, HashSet, PathBuf, Duration, SystemTime
+};
+
+use crate::{ Dxbc, IncludeType, IncludeResolver };
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct CacheKey(pub u128);
+impl CacheKey {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    const MAX_INCLUDE_DEPTH: usize = 32;
+
+    pub fn compute(
+        source: &[u8],
+        include: Option<&dyn IncludeResolver>,
+        defines: &[(String, String)],
+        entry_point: &str,
+        profile: &str,
+        flags: u32
+    ) -> Self {
+        let mut hasher = KeyHasher::build();
+        hasher.write_field(source);
+        hasher.write_field(entry_point.as_bytes());
+        hasher.write_field(profile.as_bytes());
+        hasher.write_field(&flags.to_le_bytes());
+
+        hasher.write_field(&(defines.len() as u32).to_le_bytes());
+        for (name, value) in defines.iter() {
+            hasher.write_field(name.as_bytes());
+            hasher.write_field(value.as_bytes());
+        }
+
+        if let Some(include) = include {
+            let mut visited = HashSet::new();
+            Self::hash_includes(&mut hasher, include, source, None, &mut visited, 0);
+        }
+
+        Self(hasher.finish())
+    }
+
+    pub fn file_name(&self) -> String {
+        format!("{:032x}.{}", self.0, ShaderCache::EXTENSION)
+    }
+
+    pub fn hash(data: &[u8]) -> u128 {
+        let mut hasher = KeyHasher::build();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    fn hash_includes(
+        hasher: &mut KeyHasher,
+        include: &dyn IncludeResolver,
+        source: &[u8],
+        parent: Option<&str>,
+        visited: &mut HashSet<String>,
+        depth: usize
+    ) {
+        if depth >= Self::MAX_INCLUDE_DEPTH {
+            return;
+        }
+
+        for (include_type, name) in scan_includes(source) {
+            hasher.write_field(name.as_bytes());
+            let file = match include.resolve(include_type, &name, parent) {
+                Some(file) => file,
+                None => {
+                    hasher.write_field(&[]);
+                    continue;
+                }
+            };
+
+            hasher.write_field(file.path.as_bytes());
+            hasher.write_field(&file.source);
+            if visited.insert(file.path.clone()) {
+                Self::hash_includes(hasher, include, &file.source, Some(&file.path), visited, depth + 1);
+            }
+        }
+    }
+}
+
+struct KeyHasher(u128);
+impl KeyHasher {
+    fn build() -> Self {
+        Self(CacheKey::OFFSET)
+    }
+
+    fn write(&mut self, data: &[u8]) {
+        for byte in data {
+            self.0 ^= *byte as u128;
+            self.0 = self.0.wrapping_mul(CacheKey::PRIME);
+        }
+    }
+
+    fn write_field(&mut self, data: &[u8]) {
+        self.write(&(data.len() as u64).to_le_bytes());
+        self.write(data);
+    }
+
+    fn finish(&self) -> u128 {
+        self.0
+    }
+}
+
+pub fn scan_includes(source: &[u8]) -> Vec<(IncludeType, String)> {
+    let source = String::from_utf8_lossy(source);
+
+    source
+        .lines()
+        .filter_map(|line| {
+            let directive = line.trim_start().strip_prefix('#')?.trim_start().strip_prefix("include")?.trim();
+            let (include_type, close) = match directive.chars().next()? {
+                '"' => (IncludeType::Local, '"'),
+                '<' => (IncludeType::System, '>'),
+                _ => return None
+            };
+            let name = &directive[1..];
+            let end = name.find(close)?;
+
+            Some((include_type, name[..end].to_string()))
+        })
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CacheEntry {
+    pub key: CacheKey,
+    pub bytecode: Vec<u8>
+}
+impl CacheEntry {
+    pub const MAGIC: &'static [u8; 4] = b"EDXC";
+    pub const VERSION: u32 = 1;
+    pub const HEADER_SIZE: usize = 44;
+
+    pub fn build(key: CacheKey, bytecode: &[u8]) -> Self {
+        Self { key, bytecode: bytecode.to_vec() }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(Self::HEADER_SIZE + self.bytecode.len());
+        data.extend_from_slice(Self::MAGIC);
+        data.extend_from_slice(&Self::VERSION.to_le_bytes());
+        data.extend_from_slice(&self.key.0.to_le_bytes());
+        data.extend_from_slice(&(self.bytecode.len() as u32).to_le_bytes());
+        data.extend_from_slice(&CacheKey::hash(&self.bytecode).to_le_bytes());
+        data.extend_from_slice(&self.bytecode);
+
+        data
+    }
+
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::HEADER_SIZE || &data[0..4] != Self::MAGIC {
+            return None;
+        }
+
+        let version = u32::from_le_bytes(data[4..8].try_into().ok()?);
+        let key = CacheKey(u128::from_le_bytes(data[8..24].try_into().ok()?));
+        let size = u32::from_le_bytes(data[24..28].try_into().ok()?) as usize;
+        let checksum = u128::from_le_bytes(data[28..44].try_into().ok()?);
+        let bytecode = &data[Self::HEADER_SIZE..];
+
+        if version != Self::VERSION || bytecode.len() != size || CacheKey::hash(bytecode) != checksum {
+            return None;
+        }
+        Dxbc::parse(bytecode)?;
+
+        Some(Self::build(key, bytecode))
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ShaderCache {
+    pub root: PathBuf
+}
+impl ShaderCache {
+    pub const EXTENSION: &'static str = "edxc";
+    pub const TEMP_EXTENSION: &'static str = "tmp";
+
+    pub fn build(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn path(&self, key: CacheKey) -> PathBuf {
+        self.root.join(key.file_name())
+    }
+
+    pub fn load(&self, key: CacheKey) -> Option<Vec<u8>> {
+        let path = self.path(key);
+        let data = read(&path).ok()?;
+
+        match CacheEntry::decode(&data) {
+            Some(entry) if entry.key == key => {
+                if let Ok(file) = File::options().write(true).open(&path) {
+                    let _ = file.set_modified(SystemTime::now());
+                }
+                Some(entry.bytecode)
+            },
+            _ => {
+                let _ = remove_file(&path);
+                None
+            }
+        }
+    }
+
+    pub fn store(&self, key: CacheKey, bytecode: &[u8]) -> bool {
+        let path = self.path(key);
+        let temp = path.with_extension(Self::TEMP_EXTENSION);
+
+        create_dir_all(&self.root).is_ok()
+            && write(&temp, CacheEntry::build(key, bytecode).encode()).is_ok()
+            && rename(&temp, &path).is_ok()
+    }
+
+    pub fn evict_stale(&self, max_age: Duration) -> usize {
+        let entries = match read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(_) => return 0
+        };
+        let now = SystemTime::now();
+        let mut evicted = 0;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().is_none_or(|extension| extension != Self::EXTENSION) {
+                continue;
+            }
+
+            let stale = match entry.metadata().and_then(|metadata| metadata.modified()) {
+                Ok(modified) => now.duration_since(modified).is_ok_and(|age| age > max_age),
+                Err(_) => true
+            };
+            let valid = read(&path).ok().and_then(|data| CacheEntry::decode(&data)).is_some();
+
+            if (stale || !valid) && remove_file(&path).is_ok() {
+                evicted += 1;
+            }
+        }
+
+        evicted
+    }
+
+    pub fn clear(&self) -> usize {
+        let entries = match read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(_) => return 0
+        };
+        let mut removed = 0;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let owned = path.extension().is_some_and(|extension| extension == Self::EXTENSION || extension == Self::TEMP_EXTENSION);
+
+            if owned && remove_file(&path).is_ok() {
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryInclude;
+
+    const OVERLAY_VS: &[u8] = include_bytes!("bytecode/overlay_vs.cso");
+    const SOURCE: &[u8] = b"#include \"common.hlsl\"\nfloat4 main() : SV_Target { return 0; }";
+
+    fn cache(name: &str) -> ShaderCache {
+        let root = std::env::temp_dir().join(format!("edx-shader-cache-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+
+        ShaderCache::build(root)
+    }
+
+    fn key(include: Option<&dyn IncludeResolver>, defines: &[(String, String)], flags: u32) -> CacheKey {
+        CacheKey::compute(SOURCE, include, defines, "main", "ps_5_0", flags)
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let cache = cache("round-trip");
+        let key = key(None, &[], 0);
+        assert!(cache.load(key).is_none());
+
+        assert!(cache.store(key, OVERLAY_VS));
+        assert_eq!(cache.load(key).as_deref(), Some(OVERLAY_VS));
+        assert_eq!(CacheEntry::decode(&CacheEntry::build(key, OVERLAY_VS).encode()), Some(CacheEntry::build(key, OVERLAY_VS)));
+
+        cache.clear();
+    }
+
+    #[test]
+    fn corrupted_entries_are_discarded() {
+        let cache = cache("corruption");
+        let key = key(None, &[], 0);
+        assert!(cache.store(key, OVERLAY_VS));
+
+        let path = cache.path(key);
+        let mut data = read(&path).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        write(&path, &data).unwrap();
+
+        assert!(cache.load(key).is_none());
+        assert!(!path.exists());
+
+        let entry = CacheEntry::build(key, OVERLAY_VS).encode();
+        assert!(CacheEntry::decode(&entry[..CacheEntry::HEADER_SIZE - 1]).is_none());
+        assert!(CacheEntry::decode(&entry[..entry.len() - 1]).is_none());
+
+        let mut wrong_key = CacheEntry::build(CacheKey(key.0 ^ 1), OVERLAY_VS).encode();
+        write(&path, &wrong_key).unwrap();
+        assert!(cache.load(key).is_none());
+
+        wrong_key[4] = 2;
+        assert!(CacheEntry::decode(&wrong_key).is_none());
+
+        cache.clear();
+    }
+
+    #[test]
+    fn keys_depend_on_defines_includes_and_flags() {
+        let common = MemoryInclude::build().insert("common.hlsl", b"#define SCALE 1");
+        let changed = MemoryInclude::build().insert("common.hlsl", b"#define SCALE 2");
+        let defines = [("HDR".to_string(), "1".to_string())];
+        let base = key(Some(&common), &[], 0);
+
+        assert_eq!(base, key(Some(&common), &[], 0));
+        assert_ne!(base, key(Some(&common), &defines, 0));
+        assert_ne!(key(Some(&common), &defines, 0), key(Some(&common), &[("HDR".to_string(), "0".to_string())], 0));
+        assert_ne!(base, key(Some(&changed), &[], 0));
+        assert_ne!(base, key(None, &[], 0));
+        assert_ne!(base, key(Some(&common), &[], 1));
+        assert_ne!(base, CacheKey::compute(SOURCE, Some(&common), &[], "other", "ps_5_0", 0));
+        assert_ne!(base, CacheKey::compute(SOURCE, Some(&common), &[], "main", "ps_4_0", 0));
+    }
+
+    #[test]
+    fn clear_removes_entries_and_temp_files() {
+        let cache = cache("clear");
+        let key = key(None, &[], 0);
+        assert!(cache.store(key, OVERLAY_VS));
+        assert!(cache.store(CacheKey(key.0 ^ 1), OVERLAY_VS));
+        write(cache.path(key).with_extension(ShaderCache::TEMP_EXTENSION), b"partial").unwrap();
+        write(cache.root.join("notes.txt"), b"keep").unwrap();
+
+        assert_eq!(cache.clear(), 3);
+        assert!(cache.load(key).is_none());
+        assert!(cache.root.join("notes.txt").exists());
+
+        let _ = std::fs::remove_dir_all(&cache.root);
+    }
+}
\ No newline at end of file
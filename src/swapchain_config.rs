@@ -0,0 +1,326 @@
+use crate::{ BackbufferFormat, ColorOutput };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapEffect {
+    Discard,
+    Sequential,
+    FlipSequential,
+    FlipDiscard
+}
+impl SwapEffect {
+    pub fn dxgi(&self) -> u32 {
+        match self {
+            Self::Discard => 0,
+            Self::Sequential => 1,
+            Self::FlipSequential => 3,
+            Self::FlipDiscard => 4
+        }
+    }
+
+    pub fn is_flip_model(&self) -> bool {
+        matches!(self, Self::FlipSequential | Self::FlipDiscard)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SwapchainError {
+    BufferCount { count: u32, min: u32, max: u32 },
+    SampleCount { count: u32 },
+    SyncInterval { interval: u32 },
+    UnsupportedFormat { format: BackbufferFormat },
+    FlipModelMultisample { count: u32 },
+    FlipModelFormat { format: BackbufferFormat },
+    SrgbFormat { format: BackbufferFormat },
+    TearingRequiresFlipModel,
+    Hdr10Format { format: BackbufferFormat },
+    Hdr10RequiresFlipModel,
+    FlipModelLayered
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SwapchainConfig {
+    pub swap_effect: SwapEffect,
+    pub buffer_count: u32,
+    pub format: BackbufferFormat,
+    pub srgb: bool,
+    pub sample_count: u32,
+    pub refresh_rate: [u32; 2],
+    pub allow_tearing: bool,
+    pub sync_interval: u32,
+    pub hdr10: bool
+}
+impl SwapchainConfig {
+    pub const MAX_BUFFER_COUNT: u32 = 16;
+    pub const MAX_SYNC_INTERVAL: u32 = 4;
+    pub const MAX_SAMPLE_COUNT: u32 = 32;
+
+    const SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH: u32 = 2;
+    const SWAP_CHAIN_FLAG_ALLOW_TEARING: u32 = 2048;
+    const PRESENT_ALLOW_TEARING: u32 = 0x200;
+
+    pub fn build() -> Self {
+        Self {
+            swap_effect: SwapEffect::Discard,
+            buffer_count: 1,
+            format: BackbufferFormat::Rgba8Unorm,
+            srgb: false,
+            sample_count: 1,
+            refresh_rate: [0, 1],
+            allow_tearing: false,
+            sync_interval: 1,
+            hdr10: false
+        }
+    }
+
+    pub fn flip_discard() -> Self {
+        Self::build().swap_effect(SwapEffect::FlipDiscard).buffer_count(2)
+    }
+
+    pub fn flip_sequential() -> Self {
+        Self::build().swap_effect(SwapEffect::FlipSequential).buffer_count(2)
+    }
+
+    pub fn swap_effect(mut self, swap_effect: SwapEffect) -> Self {
+        self.swap_effect = swap_effect;
+        self
+    }
+
+    pub fn buffer_count(mut self, buffer_count: u32) -> Self {
+        self.buffer_count = buffer_count;
+        self
+    }
+
+    pub fn format(mut self, format: BackbufferFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    pub fn srgb(mut self, srgb: bool) -> Self {
+        self.srgb = srgb;
+        self
+    }
+
+    pub fn sample_count(mut self, sample_count: u32) -> Self {
+        self.sample_count = sample_count;
+        self
+    }
+
+    pub fn refresh_rate(mut self, numerator: u32, denominator: u32) -> Self {
+        self.refresh_rate = [numerator, denominator];
+        self
+    }
+
+    pub fn allow_tearing(mut self, allow_tearing: bool) -> Self {
+        self.allow_tearing = allow_tearing;
+        self
+    }
+
+    pub fn sync_interval(mut self, sync_interval: u32) -> Self {
+        self.sync_interval = sync_interval;
+        self
+    }
+
+    pub fn hdr10(mut self, hdr10: bool) -> Self {
+        self.hdr10 = hdr10;
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), SwapchainError> {
+        let flip_model = self.swap_effect.is_flip_model();
+        let min_buffers = if flip_model { 2 } else { 1 };
+
+        if self.buffer_count < min_buffers || self.buffer_count > Self::MAX_BUFFER_COUNT {
+            return Err(SwapchainError::BufferCount { count: self.buffer_count, min: min_buffers, max: Self::MAX_BUFFER_COUNT });
+        }
+        if self.sample_count == 0 || self.sample_count > Self::MAX_SAMPLE_COUNT || !self.sample_count.is_power_of_two() {
+            return Err(SwapchainError::SampleCount { count: self.sample_count });
+        }
+        if self.sync_interval > Self::MAX_SYNC_INTERVAL {
+            return Err(SwapchainError::SyncInterval { interval: self.sync_interval });
+        }
+        if matches!(self.format, BackbufferFormat::Bgrx8Unorm | BackbufferFormat::Bgrx8UnormSrgb | BackbufferFormat::Other(_)) {
+            return Err(SwapchainError::UnsupportedFormat { format: self.format });
+        }
+        if self.srgb && self.srgb_format().is_none() {
+            return Err(SwapchainError::SrgbFormat { format: self.format });
+        }
+        if flip_model && self.sample_count > 1 {
+            return Err(SwapchainError::FlipModelMultisample { count: self.sample_count });
+        }
+        if flip_model && matches!(self.format, BackbufferFormat::Rgba8UnormSrgb | BackbufferFormat::Bgra8UnormSrgb) {
+            return Err(SwapchainError::FlipModelFormat { format: self.format });
+        }
+        if self.allow_tearing && !flip_model {
+            return Err(SwapchainError::TearingRequiresFlipModel);
+        }
+        if self.hdr10 && self.format != BackbufferFormat::Rgb10A2Unorm {
+            return Err(SwapchainError::Hdr10Format { format: self.format });
+        }
+        if self.hdr10 && !flip_model {
+            return Err(SwapchainError::Hdr10RequiresFlipModel);
+        }
+
+        Ok(())
+    }
+
+    /// Layered windows are composed from their GDI redirection surface, which flip-model swapchains bypass,
+    /// so per-pixel alpha only works with the blt model.
+    pub fn validate_layered(&self) -> Result<(), SwapchainError> {
+        self.validate()?;
+
+        if self.swap_effect.is_flip_model() {
+            return Err(SwapchainError::FlipModelLayered);
+        }
+
+        Ok(())
+    }
+
+    pub fn tearing_support(self, supported: bool) -> Self {
+        let allow_tearing = self.allow_tearing && supported;
+        self.allow_tearing(allow_tearing)
+    }
+
+    pub fn color_space(&self) -> Option<u32> {
+        match self.hdr10 {
+            true => Some(ColorOutput::COLOR_SPACE_G2084_P2020),
+            false => None
+        }
+    }
+
+    pub fn buffer_format(&self) -> BackbufferFormat {
+        match (self.srgb, self.swap_effect.is_flip_model(), self.srgb_format()) {
+            (true, false, Some(format)) => format,
+            _ => self.format
+        }
+    }
+
+    pub fn view_format(&self) -> BackbufferFormat {
+        match (self.srgb, self.srgb_format()) {
+            (true, Some(format)) => format,
+            _ => self.format
+        }
+    }
+
+    pub fn flags(&self) -> u32 {
+        match self.allow_tearing {
+            true => Self::SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH | Self::SWAP_CHAIN_FLAG_ALLOW_TEARING,
+            false => Self::SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH
+        }
+    }
+
+    pub fn present_flags(&self) -> u32 {
+        match self.allow_tearing && self.sync_interval == 0 {
+            true => Self::PRESENT_ALLOW_TEARING,
+            false => 0
+        }
+    }
+
+    fn srgb_format(&self) -> Option<BackbufferFormat> {
+        match self.format {
+            BackbufferFormat::Rgba8Unorm | BackbufferFormat::Rgba8UnormSrgb => Some(BackbufferFormat::Rgba8UnormSrgb),
+            BackbufferFormat::Bgra8Unorm | BackbufferFormat::Bgra8UnormSrgb => Some(BackbufferFormat::Bgra8UnormSrgb),
+            _ => None
+        }
+    }
+}
+impl Default for SwapchainConfig {
+    fn default() -> Self {
+        Self::build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layered_windows_reject_flip_model() {
+        assert_eq!(SwapchainConfig::build().validate_layered(), Ok(()));
+        assert_eq!(SwapchainConfig::flip_discard().validate(), Ok(()));
+        assert_eq!(SwapchainConfig::flip_discard().validate_layered(), Err(SwapchainError::FlipModelLayered));
+        assert_eq!(SwapchainConfig::flip_sequential().validate_layered(), Err(SwapchainError::FlipModelLayered));
+        assert_eq!(SwapchainConfig::build().buffer_count(0).validate_layered(), Err(SwapchainError::BufferCount { count: 0, min: 1, max: 16 }));
+    }
+
+    #[test]
+    fn accepts_default_configs() {
+        assert_eq!(SwapchainConfig::build().validate(), Ok(()));
+        assert_eq!(SwapchainConfig::flip_sequential().validate(), Ok(()));
+        assert_eq!(SwapchainConfig::build().sample_count(4).srgb(true).validate(), Ok(()));
+        assert_eq!(SwapchainConfig::flip_discard().allow_tearing(true).sync_interval(0).validate(), Ok(()));
+    }
+
+    #[test]
+    fn rejects_flip_model_with_msaa() {
+        assert_eq!(SwapchainConfig::flip_discard().sample_count(4).validate(), Err(SwapchainError::FlipModelMultisample { count: 4 }));
+        assert_eq!(SwapchainConfig::flip_sequential().sample_count(2).validate(), Err(SwapchainError::FlipModelMultisample { count: 2 }));
+        assert_eq!(SwapchainConfig::build().sample_count(3).validate(), Err(SwapchainError::SampleCount { count: 3 }));
+        assert_eq!(SwapchainConfig::build().sample_count(0).validate(), Err(SwapchainError::SampleCount { count: 0 }));
+        assert_eq!(SwapchainConfig::build().sample_count(64).validate(), Err(SwapchainError::SampleCount { count: 64 }));
+    }
+
+    #[test]
+    fn rejects_flip_model_with_srgb_buffers() {
+        for format in [BackbufferFormat::Rgba8UnormSrgb, BackbufferFormat::Bgra8UnormSrgb] {
+            assert_eq!(SwapchainConfig::flip_discard().format(format).validate(), Err(SwapchainError::FlipModelFormat { format }));
+            assert_eq!(SwapchainConfig::build().format(format).validate(), Ok(()));
+        }
+
+        let config = SwapchainConfig::flip_discard().srgb(true);
+        assert_eq!(config.validate(), Ok(()));
+        assert_eq!(config.buffer_format(), BackbufferFormat::Rgba8Unorm);
+        assert_eq!(config.view_format(), BackbufferFormat::Rgba8UnormSrgb);
+        assert_eq!(SwapchainConfig::build().srgb(true).buffer_format(), BackbufferFormat::Rgba8UnormSrgb);
+        assert_eq!(SwapchainConfig::build().format(BackbufferFormat::Rgba16Float).srgb(true).validate(), Err(SwapchainError::SrgbFormat { format: BackbufferFormat::Rgba16Float }));
+    }
+
+    #[test]
+    fn tearing_requires_flip_model() {
+        assert_eq!(SwapchainConfig::build().allow_tearing(true).validate(), Err(SwapchainError::TearingRequiresFlipModel));
+
+        let config = SwapchainConfig::flip_discard().allow_tearing(true).sync_interval(0);
+        assert_eq!(config.present_flags(), SwapchainConfig::PRESENT_ALLOW_TEARING);
+        assert_eq!(config.sync_interval(1).present_flags(), 0);
+        assert_ne!(config.flags() & SwapchainConfig::SWAP_CHAIN_FLAG_ALLOW_TEARING, 0);
+    }
+
+    #[test]
+    fn hdr10_requires_ten_bit_flip_swapchains() {
+        let config = SwapchainConfig::flip_discard().format(BackbufferFormat::Rgb10A2Unorm);
+        assert_eq!(config.color_space(), None);
+        assert_eq!(config.hdr10(true).validate(), Ok(()));
+        assert_eq!(config.hdr10(true).color_space(), Some(ColorOutput::COLOR_SPACE_G2084_P2020));
+
+        assert_eq!(SwapchainConfig::flip_discard().hdr10(true).validate(), Err(SwapchainError::Hdr10Format { format: BackbufferFormat::Rgba8Unorm }));
+        assert_eq!(SwapchainConfig::build().format(BackbufferFormat::Rgb10A2Unorm).hdr10(true).validate(), Err(SwapchainError::Hdr10RequiresFlipModel));
+    }
+
+    #[test]
+    fn drops_unsupported_tearing() {
+        let config = SwapchainConfig::flip_discard().allow_tearing(true).sync_interval(0);
+
+        assert!(config.tearing_support(true).allow_tearing);
+        assert!(!config.tearing_support(false).allow_tearing);
+        assert_eq!(config.tearing_support(false).flags(), SwapchainConfig::SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH);
+        assert_eq!(config.tearing_support(false).present_flags(), 0);
+        assert!(!SwapchainConfig::flip_discard().tearing_support(true).allow_tearing);
+    }
+
+    #[test]
+    fn bounds_buffer_count_and_sync_interval() {
+        assert_eq!(SwapchainConfig::build().buffer_count(0).validate(), Err(SwapchainError::BufferCount { count: 0, min: 1, max: 16 }));
+        assert_eq!(SwapchainConfig::flip_discard().buffer_count(1).validate(), Err(SwapchainError::BufferCount { count: 1, min: 2, max: 16 }));
+        assert_eq!(SwapchainConfig::flip_discard().buffer_count(16).validate(), Ok(()));
+        assert_eq!(SwapchainConfig::build().buffer_count(17).validate(), Err(SwapchainError::BufferCount { count: 17, min: 1, max: 16 }));
+
+        assert_eq!(SwapchainConfig::build().sync_interval(4).validate(), Ok(()));
+        assert_eq!(SwapchainConfig::build().sync_interval(5).validate(), Err(SwapchainError::SyncInterval { interval: 5 }));
+    }
+
+    #[test]
+    fn rejects_unsupported_formats() {
+        for format in [BackbufferFormat::Bgrx8Unorm, BackbufferFormat::Bgrx8UnormSrgb, BackbufferFormat::Other(2)] {
+            assert_eq!(SwapchainConfig::build().format(format).validate(), Err(SwapchainError::UnsupportedFormat { format }));
+        }
+    }
+}
\ No newline at end of file
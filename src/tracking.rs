@@ -0,0 +1,245 @@
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScreenRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32
+}
+impl ScreenRect {
+    pub fn build(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn from_edges(left: i32, top: i32, right: i32, bottom: i32) -> Self {
+        Self::build(left, top, right - left, bottom - top)
+    }
+
+    pub fn right(&self) -> i32 {
+        self.x + self.width
+    }
+
+    pub fn bottom(&self) -> i32 {
+        self.y + self.height
+    }
+
+    pub fn size(&self) -> [u32; 2] {
+        [self.width.max(0) as u32, self.height.max(0) as u32]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    pub fn contains(&self, other: &ScreenRect) -> bool {
+        other.x >= self.x && other.y >= self.y && other.right() <= self.right() && other.bottom() <= self.bottom()
+    }
+
+    pub fn intersects(&self, other: &ScreenRect) -> bool {
+        other.x < self.right() && other.right() > self.x && other.y < self.bottom() && other.bottom() > self.y
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TargetQuery {
+    Title(String),
+    Class(String),
+    ProcessId(u32)
+}
+impl TargetQuery {
+    pub fn matches(&self, title: &str, class: &str, process_id: u32) -> bool {
+        match self {
+            Self::Title(query) => !query.is_empty() && title.contains(query.as_str()),
+            Self::Class(query) => class == query,
+            Self::ProcessId(query) => process_id == *query
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TargetSnapshot {
+    pub client: ScreenRect,
+    pub minimized: bool,
+    pub visible: bool,
+    pub foreground: Option<ScreenRect>
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OcclusionPolicy {
+    Ignore,
+    Foreground,
+    Covered
+}
+impl OcclusionPolicy {
+    pub fn is_occluded(&self, target: &TargetSnapshot) -> bool {
+        match (self, target.foreground) {
+            (Self::Ignore, _) | (_, None) => false,
+            (Self::Foreground, Some(_)) => true,
+            (Self::Covered, Some(foreground)) => foreground.contains(&target.client)
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct TrackingUpdate {
+    pub visibility: Option<bool>,
+    pub moved: Option<ScreenRect>,
+    pub resized: Option<[u32; 2]>,
+    pub lost: bool
+}
+impl TrackingUpdate {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OverlayTracker {
+    pub policy: OcclusionPolicy,
+    pub rect: Option<ScreenRect>,
+    pub visible: bool,
+    pub lost: bool
+}
+impl OverlayTracker {
+    pub fn build(policy: OcclusionPolicy) -> Self {
+        Self { policy, rect: None, visible: false, lost: true }
+    }
+
+    pub fn update(&mut self, target: Option<TargetSnapshot>) -> TrackingUpdate {
+        let mut update = TrackingUpdate::default();
+
+        let target = match target {
+            Some(target) => target,
+            None => {
+                update.lost = !self.lost;
+                update.visibility = self.set_visible(false);
+                self.lost = true;
+                return update;
+            }
+        };
+        self.lost = false;
+
+        let visible = target.visible && !target.minimized && !target.client.is_empty() && !self.policy.is_occluded(&target);
+
+        if visible && self.rect != Some(target.client) {
+            update.moved = Some(target.client);
+            if self.rect.map(|rect| rect.size()) != Some(target.client.size()) {
+                update.resized = Some(target.client.size());
+            }
+            self.rect = Some(target.client);
+        }
+        update.visibility = self.set_visible(visible);
+
+        update
+    }
+
+    fn set_visible(&mut self, visible: bool) -> Option<bool> {
+        if self.visible == visible {
+            return None;
+        }
+
+        self.visible = visible;
+        Some(visible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(client: ScreenRect) -> TargetSnapshot {
+        TargetSnapshot { client, minimized: false, visible: true, foreground: None }
+    }
+
+    fn tracker(policy: OcclusionPolicy) -> OverlayTracker {
+        let mut tracker = OverlayTracker::build(policy);
+        tracker.update(Some(target(ScreenRect::build(0, 0, 800, 600))));
+
+        tracker
+    }
+
+    #[test]
+    fn first_update_shows_and_sizes() {
+        let mut tracker = OverlayTracker::build(OcclusionPolicy::Ignore);
+        let rect = ScreenRect::build(10, 20, 800, 600);
+
+        assert_eq!(tracker.update(Some(target(rect))), TrackingUpdate {
+            visibility: Some(true),
+            moved: Some(rect),
+            resized: Some([800, 600]),
+            lost: false
+        });
+        assert!(tracker.update(Some(target(rect))).is_empty());
+    }
+
+    #[test]
+    fn tracks_moves_and_resizes() {
+        let mut tracker = tracker(OcclusionPolicy::Ignore);
+
+        let moved = ScreenRect::build(100, 50, 800, 600);
+        assert_eq!(tracker.update(Some(target(moved))), TrackingUpdate { moved: Some(moved), ..TrackingUpdate::default() });
+
+        let resized = ScreenRect::build(100, 50, 1024, 768);
+        assert_eq!(tracker.update(Some(target(resized))), TrackingUpdate { moved: Some(resized), resized: Some([1024, 768]), ..TrackingUpdate::default() });
+        assert_eq!(tracker.rect, Some(resized));
+    }
+
+    #[test]
+    fn hides_while_minimized_or_invisible() {
+        let mut tracker = tracker(OcclusionPolicy::Ignore);
+        let rect = ScreenRect::build(0, 0, 800, 600);
+
+        let minimized = TargetSnapshot { minimized: true, ..target(ScreenRect::build(-32000, -32000, 160, 28)) };
+        assert_eq!(tracker.update(Some(minimized)), TrackingUpdate { visibility: Some(false), ..TrackingUpdate::default() });
+        assert!(tracker.update(Some(minimized)).is_empty());
+        assert_eq!(tracker.rect, Some(rect));
+
+        assert_eq!(tracker.update(Some(target(rect))), TrackingUpdate { visibility: Some(true), ..TrackingUpdate::default() });
+
+        let hidden = TargetSnapshot { visible: false, ..target(rect) };
+        assert_eq!(tracker.update(Some(hidden)).visibility, Some(false));
+        assert_eq!(tracker.update(Some(target(ScreenRect::build(0, 0, 0, 600)))), TrackingUpdate::default());
+    }
+
+    #[test]
+    fn reports_target_loss_once() {
+        let mut tracker = tracker(OcclusionPolicy::Ignore);
+
+        assert_eq!(tracker.update(None), TrackingUpdate { visibility: Some(false), lost: true, ..TrackingUpdate::default() });
+        assert!(tracker.update(None).is_empty());
+        assert!(tracker.lost);
+
+        let rect = ScreenRect::build(0, 0, 800, 600);
+        assert_eq!(tracker.update(Some(target(rect))), TrackingUpdate { visibility: Some(true), ..TrackingUpdate::default() });
+        assert!(!tracker.lost);
+
+        let mut tracker = OverlayTracker::build(OcclusionPolicy::Ignore);
+        assert!(tracker.update(None).is_empty());
+    }
+
+    #[test]
+    fn occlusion_policies() {
+        let client = ScreenRect::build(100, 100, 800, 600);
+        let partial = TargetSnapshot { foreground: Some(ScreenRect::build(0, 0, 400, 400)), ..target(client) };
+        let covered = TargetSnapshot { foreground: Some(ScreenRect::build(0, 0, 1920, 1080)), ..target(client) };
+
+        assert!(!OcclusionPolicy::Ignore.is_occluded(&covered));
+        assert!(!OcclusionPolicy::Foreground.is_occluded(&target(client)));
+        assert!(OcclusionPolicy::Foreground.is_occluded(&partial));
+        assert!(!OcclusionPolicy::Covered.is_occluded(&partial));
+        assert!(OcclusionPolicy::Covered.is_occluded(&covered));
+
+        let mut tracker = tracker(OcclusionPolicy::Covered);
+        assert_eq!(tracker.update(Some(TargetSnapshot { foreground: Some(ScreenRect::build(0, 0, 1920, 1080)), ..target(ScreenRect::build(0, 0, 800, 600)) })).visibility, Some(false));
+        assert_eq!(tracker.update(Some(target(ScreenRect::build(0, 0, 800, 600)))).visibility, Some(true));
+    }
+
+    #[test]
+    fn target_queries() {
+        assert!(TargetQuery::Title("Game".to_string()).matches("My Game - 1.0", "", 0));
+        assert!(!TargetQuery::Title(String::new()).matches("My Game", "", 0));
+        assert!(TargetQuery::Class("UnityWndClass".to_string()).matches("", "UnityWndClass", 0));
+        assert!(!TargetQuery::Class("Unity".to_string()).matches("", "UnityWndClass", 0));
+        assert!(TargetQuery::ProcessId(42).matches("", "", 42));
+    }
+}
\ No newline at end of file
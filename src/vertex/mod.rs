@@ -0,0 +1,97 @@
+mod vertex_format;
+pub use vertex_format::{ VertexFormat, VertexElement, VertexAttribute, ElementFormat };
+
+mod packed_color;
+pub use packed_color::PackedColor;
+
+use edx_derive::VertexFormat;
+
+#[repr(C)]
+#[derive(Clone, Copy, VertexFormat)]
+pub struct Vertex {
+    #[semantic("POSITION", 0)]
+    pub position: [f32; 3],
+    #[semantic("COLOR", 0)]
+    pub color: [f32; 4]
+}
+unsafe impl Send for Vertex {}
+unsafe impl Sync for Vertex {}
+
+#[repr(C)]
+#[derive(Clone, Copy, VertexFormat)]
+pub struct Vertex2D {
+    #[semantic("POSITION", 0)]
+    pub position: [f32; 2],
+    #[semantic("COLOR", 0)]
+    pub color: PackedColor
+}
+impl Vertex2D {
+    pub fn build(position: [f32; 2], color: impl Into<PackedColor>) -> Self {
+        Self { position, color: color.into() }
+    }
+}
+unsafe impl Send for Vertex2D {}
+unsafe impl Sync for Vertex2D {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShaderReflection;
+    use std::mem::{ offset_of, size_of };
+
+    const OVERLAY_VS: &[u8] = include_bytes!("../shader/bytecode/overlay_vs.cso");
+    const OVERLAY_2D_VS: &[u8] = include_bytes!("../shader/bytecode/overlay_2d_vs.cso");
+
+    #[repr(C)]
+    #[derive(Clone, Copy, VertexFormat)]
+    struct Mixed {
+        #[semantic("POSITION")]
+        position: [f32; 3],
+        #[semantic("TEXCOORD", 1)]
+        weight: f32,
+        #[semantic("BLENDINDICES")]
+        indices: [u32; 4],
+        #[semantic("OFFSET", 2)]
+        offset: [i32; 2],
+        #[semantic("COLOR")]
+        color: PackedColor
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy, VertexFormat)]
+    struct IntegerPosition {
+        #[semantic("POSITION")]
+        position: [i32; 3],
+        #[semantic("COLOR")]
+        color: [f32; 4]
+    }
+
+    #[test]
+    fn derives_offsets_and_formats() {
+        let elements = Mixed::elements();
+        let described: Vec<(&str, u32, ElementFormat, u32)> = elements.iter().map(|element| (element.semantic_name, element.semantic_index, element.format, element.offset)).collect();
+
+        assert_eq!(described, vec![
+            ("POSITION", 0, ElementFormat::Float32x3, offset_of!(Mixed, position) as u32),
+            ("TEXCOORD", 1, ElementFormat::Float32, offset_of!(Mixed, weight) as u32),
+            ("BLENDINDICES", 0, ElementFormat::Uint32x4, offset_of!(Mixed, indices) as u32),
+            ("OFFSET", 2, ElementFormat::Sint32x2, offset_of!(Mixed, offset) as u32),
+            ("COLOR", 0, ElementFormat::Unorm8x4, offset_of!(Mixed, color) as u32)
+        ]);
+        assert_eq!(Mixed::stride() as usize, size_of::<Mixed>());
+        assert_eq!(Vertex2D::elements()[1].offset, 8);
+    }
+
+    #[test]
+    fn matches_bundled_reflection() {
+        let overlay = ShaderReflection::parse(OVERLAY_VS).unwrap();
+        let overlay_2d = ShaderReflection::parse(OVERLAY_2D_VS).unwrap();
+
+        assert!(Vertex::matches(&overlay));
+        assert!(Vertex::matches(&overlay_2d));
+        assert!(Vertex2D::matches(&overlay_2d));
+        assert!(!Vertex2D::matches(&overlay));
+        assert!(!IntegerPosition::matches(&overlay));
+        assert!(Mixed::matches(&overlay));
+    }
+}
\ No newline at end of file
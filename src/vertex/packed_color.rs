@@ -0,0 +1,43 @@
+use crate::{ ElementFormat, VertexAttribute };
+
+/// Stores R, G, B, A as bytes in memory order to match `R8G8B8A8_UNORM`, so the raw `u32` reads as `0xAABBGGRR`
+/// on little-endian targets. `Color::from_u32`/`to_u32` use `0xRRGGBBAA` instead.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct PackedColor(pub u32);
+impl PackedColor {
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self(u32::from_le_bytes([r, g, b, a]))
+    }
+
+    pub fn from_f32(color: [f32; 4]) -> Self {
+        let [r, g, b, a] = color.map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+        Self::from_rgba8(r, g, b, a)
+    }
+
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn to_f32(&self) -> [f32; 4] {
+        self.to_rgba8().map(|channel| channel as f32 / 255.0)
+    }
+}
+impl From<[f32; 4]> for PackedColor {
+    fn from(color: [f32; 4]) -> Self {
+        Self::from_f32(color)
+    }
+}
+impl From<[u8; 4]> for PackedColor {
+    fn from(color: [u8; 4]) -> Self {
+        Self(u32::from_le_bytes(color))
+    }
+}
+impl From<PackedColor> for [f32; 4] {
+    fn from(color: PackedColor) -> Self {
+        color.to_f32()
+    }
+}
+impl VertexAttribute for PackedColor {
+    const FORMAT: ElementFormat = ElementFormat::Unorm8x4;
+}
\ No newline at end of file
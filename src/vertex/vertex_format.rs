@@ -0,0 +1,188 @@
+#[cfg(windows)]
+use crate::dependencies::{
+    CString, DXGI_FORMAT, D3D11_INPUT_ELEMENT_DESC, D3D11_INPUT_PER_VERTEX_DATA, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32G32B32_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT, DXGI_FORMAT_R32_UINT, DXGI_FORMAT_R32G32_UINT, DXGI_FORMAT_R32G32B32_UINT, DXGI_FORMAT_R32G32B32A32_UINT, DXGI_FORMAT_R32_SINT, DXGI_FORMAT_R32G32_SINT, DXGI_FORMAT_R32G32B32_SINT, DXGI_FORMAT_R32G32B32A32_SINT
+};
+
+use crate::dependencies::{
+    size_of
+};
+
+use crate::{ ComponentType, ShaderReflection };
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ElementFormat {
+    Float32,
+    Float32x2,
+    Float32x3,
+    Float32x4,
+    Uint32,
+    Uint32x2,
+    Uint32x3,
+    Uint32x4,
+    Sint32,
+    Sint32x2,
+    Sint32x3,
+    Sint32x4,
+    Unorm8x4
+}
+impl ElementFormat {
+    pub fn component_type(&self) -> ComponentType {
+        match self {
+            Self::Float32 | Self::Float32x2 | Self::Float32x3 | Self::Float32x4 | Self::Unorm8x4 => ComponentType::Float32,
+            Self::Uint32 | Self::Uint32x2 | Self::Uint32x3 | Self::Uint32x4 => ComponentType::Uint32,
+            Self::Sint32 | Self::Sint32x2 | Self::Sint32x3 | Self::Sint32x4 => ComponentType::Sint32
+        }
+    }
+
+    pub fn component_count(&self) -> u32 {
+        match self {
+            Self::Float32 | Self::Uint32 | Self::Sint32 => 1,
+            Self::Float32x2 | Self::Uint32x2 | Self::Sint32x2 => 2,
+            Self::Float32x3 | Self::Uint32x3 | Self::Sint32x3 => 3,
+            Self::Float32x4 | Self::Uint32x4 | Self::Sint32x4 | Self::Unorm8x4 => 4
+        }
+    }
+
+    pub fn size(&self) -> u32 {
+        match self {
+            Self::Unorm8x4 => 4,
+            format => format.component_count() * 4
+        }
+    }
+}
+
+#[cfg(windows)]
+impl ElementFormat {
+    pub fn dxgi_format(&self) -> DXGI_FORMAT {
+        match self {
+            Self::Float32 => DXGI_FORMAT_R32_FLOAT,
+            Self::Float32x2 => DXGI_FORMAT_R32G32_FLOAT,
+            Self::Float32x3 => DXGI_FORMAT_R32G32B32_FLOAT,
+            Self::Float32x4 => DXGI_FORMAT_R32G32B32A32_FLOAT,
+            Self::Uint32 => DXGI_FORMAT_R32_UINT,
+            Self::Uint32x2 => DXGI_FORMAT_R32G32_UINT,
+            Self::Uint32x3 => DXGI_FORMAT_R32G32B32_UINT,
+            Self::Uint32x4 => DXGI_FORMAT_R32G32B32A32_UINT,
+            Self::Sint32 => DXGI_FORMAT_R32_SINT,
+            Self::Sint32x2 => DXGI_FORMAT_R32G32_SINT,
+            Self::Sint32x3 => DXGI_FORMAT_R32G32B32_SINT,
+            Self::Sint32x4 => DXGI_FORMAT_R32G32B32A32_SINT,
+            Self::Unorm8x4 => DXGI_FORMAT_R8G8B8A8_UNORM
+        }
+    }
+}
+
+pub trait VertexAttribute {
+    const FORMAT: ElementFormat;
+}
+impl VertexAttribute for f32 { const FORMAT: ElementFormat = ElementFormat::Float32; }
+impl VertexAttribute for [f32; 1] { const FORMAT: ElementFormat = ElementFormat::Float32; }
+impl VertexAttribute for [f32; 2] { const FORMAT: ElementFormat = ElementFormat::Float32x2; }
+impl VertexAttribute for [f32; 3] { const FORMAT: ElementFormat = ElementFormat::Float32x3; }
+impl VertexAttribute for [f32; 4] { const FORMAT: ElementFormat = ElementFormat::Float32x4; }
+impl VertexAttribute for u32 { const FORMAT: ElementFormat = ElementFormat::Uint32; }
+impl VertexAttribute for [u32; 1] { const FORMAT: ElementFormat = ElementFormat::Uint32; }
+impl VertexAttribute for [u32; 2] { const FORMAT: ElementFormat = ElementFormat::Uint32x2; }
+impl VertexAttribute for [u32; 3] { const FORMAT: ElementFormat = ElementFormat::Uint32x3; }
+impl VertexAttribute for [u32; 4] { const FORMAT: ElementFormat = ElementFormat::Uint32x4; }
+impl VertexAttribute for i32 { const FORMAT: ElementFormat = ElementFormat::Sint32; }
+impl VertexAttribute for [i32; 1] { const FORMAT: ElementFormat = ElementFormat::Sint32; }
+impl VertexAttribute for [i32; 2] { const FORMAT: ElementFormat = ElementFormat::Sint32x2; }
+impl VertexAttribute for [i32; 3] { const FORMAT: ElementFormat = ElementFormat::Sint32x3; }
+impl VertexAttribute for [i32; 4] { const FORMAT: ElementFormat = ElementFormat::Sint32x4; }
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct VertexElement {
+    pub semantic_name: &'static str,
+    pub semantic_index: u32,
+    pub format: ElementFormat,
+    pub offset: u32
+}
+
+/// # Safety
+///
+/// `elements` must describe fields that lie inside `Self` at the given offsets,
+/// because vertices are uploaded to the GPU as raw bytes.
+///
+/// # Deriving
+///
+/// `#[derive(VertexFormat)]` fills `elements` in from `#[semantic("NAME", index)]` field attributes:
+///
+/// ```
+/// #[repr(C)]
+/// #[derive(Clone, Copy, edx::VertexFormat)]
+/// struct Sprite {
+///     #[semantic("POSITION", 0)]
+///     position: [f32; 2],
+///     #[semantic("TEXCOORD")]
+///     uv: [f32; 2]
+/// }
+/// ```
+///
+/// The struct has to be `#[repr(C)]`:
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy, edx::VertexFormat)]
+/// struct Sprite {
+///     #[semantic("POSITION", 0)]
+///     position: [f32; 2]
+/// }
+/// ```
+///
+/// and every field needs a `#[semantic]`:
+///
+/// ```compile_fail
+/// #[repr(C)]
+/// #[derive(Clone, Copy, edx::VertexFormat)]
+/// struct Sprite {
+///     #[semantic("POSITION", 0)]
+///     position: [f32; 2],
+///     uv: [f32; 2]
+/// }
+/// ```
+pub unsafe trait VertexFormat: Copy + 'static {
+    fn elements() -> Vec<VertexElement>;
+
+    fn stride() -> u32 {
+        size_of::<Self>() as u32
+    }
+
+    fn matches(reflection: &ShaderReflection) -> bool {
+        let elements = Self::elements();
+
+        reflection.input_elements().iter().all(|input| {
+            elements.iter().any(|element| {
+                element.semantic_name.eq_ignore_ascii_case(&input.semantic_name)
+                    && element.semantic_index == input.semantic_index
+                    && element.format.component_type() == input.component_type
+                    && element.format.component_count() >= input.component_count
+            })
+        })
+    }
+
+    #[cfg(windows)]
+    fn input_layout() -> Option<(Vec<CString>, Vec<D3D11_INPUT_ELEMENT_DESC>)> {
+        let elements = Self::elements();
+
+        let mut names = Vec::with_capacity(elements.len());
+        for element in elements.iter() {
+            names.push(CString::new(element.semantic_name).ok()?);
+        }
+
+        let descs = elements
+            .iter()
+            .zip(names.iter())
+            .map(|(element, name)| D3D11_INPUT_ELEMENT_DESC {
+                SemanticName: name.as_ptr(),
+                SemanticIndex: element.semantic_index,
+                Format: element.format.dxgi_format(),
+                InputSlot: 0,
+                AlignedByteOffset: element.offset,
+                InputSlotClass: D3D11_INPUT_PER_VERTEX_DATA,
+                InstanceDataStepRate: 0
+            })
+            .collect();
+
+        Some((names, descs))
+    }
+}
\ No newline at end of file
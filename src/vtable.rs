@@ -2,13 +2,16 @@ use crate::dependencies::{
     null_mut, copy_nonoverlapping, VirtualAlloc, SUCCEEDED, IDXGIFactory, Interface, MEM_COMMIT, PAGE_READWRITE
 };
 
-use crate::{ DirectX };
+use crate::{ DirectX, ComPtr };
 
 #[derive(Clone)]
 pub struct VTable {
     pub ptr: *mut usize
 }
 impl VTable {
+    pub const PRESENT: usize = 8;
+    pub const RESIZE_BUFFERS: usize = 13;
+
     pub fn get_swapchain(dx: &DirectX) -> Option<Self> {
         const SWAPCHAIN_METHODS: usize = 18;
         const DEVICE_METHODS: usize = 43;
@@ -24,9 +27,9 @@ impl VTable {
 
         if vtable.is_null() { return None };
 
-        let swapchain_vtable = unsafe { *(dx.dxgi.swapchain as *mut *mut usize) };
-        let device_vtable = unsafe { *(dx.d3d.device as *mut *mut usize) };
-        let context_vtable = unsafe { *(dx.d3d.context as *mut *mut usize) };
+        let swapchain_vtable = unsafe { *(dx.dxgi.swapchain.as_ptr() as *mut *mut usize) };
+        let device_vtable = unsafe { *(dx.d3d.device.as_ptr() as *mut *mut usize) };
+        let context_vtable = unsafe { *(dx.d3d.context.as_ptr() as *mut *mut usize) };
 
         unsafe {
             copy_nonoverlapping(
@@ -53,14 +56,15 @@ impl VTable {
 
     pub fn get_factory(dx: &DirectX) -> Option<Self> {
         let mut factory: *mut IDXGIFactory = null_mut();
-        let hr = unsafe { (*dx.dxgi.swapchain).GetParent(
+        let hr = unsafe { dx.dxgi.swapchain.GetParent(
             &IDXGIFactory::uuidof(),
             &mut factory as *mut _ as *mut *mut _,
         ) };
 
-        if !SUCCEEDED(hr) || factory.is_null() { return None };
+        if !SUCCEEDED(hr) { return None };
 
-        let vtable = unsafe { *(factory as *mut *mut usize) };
+        let factory = unsafe { ComPtr::from_raw(factory) }?;
+        let vtable = unsafe { *(factory.as_ptr() as *mut *mut usize) };
 
         Some(Self { ptr: vtable })
     }
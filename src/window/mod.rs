@@ -4,32 +4,228 @@ pub use window_class_handle::WindowClassHandle;
 mod window_handle;
 pub use window_handle::WindowHandle;
 
-use crate::{ DirectX };
+mod window_builder;
+pub use window_builder::WindowBuilder;
+
+mod window_ref;
+pub use window_ref::WindowRef;
+
+mod target_window;
+pub use target_window::TargetWindow;
+
+mod window_proc;
+pub use window_proc::{ window_proc, EventQueue };
+
+use crate::dependencies::{
+    OsStr, OsStrExt, once, null_mut, zeroed, Arc, DWORD, LONG_PTR, MSG, WM_QUIT, GWL_EXSTYLE, WS_EX_LAYERED, WS_EX_TRANSPARENT, GetMessageW, PeekMessageW, PM_REMOVE, TranslateMessage, DispatchMessageW, GetWindowLongPtrW, SetWindowLongPtrW, SetWindowPos, ShowWindow, SW_HIDE, SW_SHOWNOACTIVATE, SWP_NOACTIVATE, HWND_TOPMOST
+};
+
+use crate::{ DirectX, DeviceState, Renderer, Color, Event, ControlFlow, TargetQuery, TrackingUpdate, Adapter, AdapterPreference };
+
+pub(crate) fn wide_string(value: &str) -> Vec<u16> {
+    OsStr::new(value)
+        .encode_wide()
+        .chain(once(0))
+        .collect()
+}
 
-#[derive(Clone)]
 pub struct Window {
-    pub class_handle: WindowClassHandle,
+    pub directx: Option<DirectX>,
+    pub recovery: Option<Box<DeviceState>>,
     pub handle: WindowHandle,
-    pub directx: DirectX
+    pub class_handle: WindowClassHandle,
+    pub events: Arc<EventQueue>,
+    pub target: Option<TargetWindow>,
+    pub adapter: AdapterPreference
 }
 impl Window {
     pub fn create(name: &str) -> Option<Self> {
-        let mut class_handle = WindowClassHandle::build(name);
-        class_handle.register();
+        WindowBuilder::build(name).create()
+    }
+
+    pub fn borrow(&self) -> Option<WindowRef<'_>> {
+        self.handle.borrow()
+    }
+
+    pub fn is_overlay(&self) -> bool {
+        self.handle.extended_style & WS_EX_LAYERED != 0
+    }
+
+    pub fn is_click_through(&self) -> bool {
+        self.handle.extended_style & WS_EX_TRANSPARENT != 0
+    }
+
+    pub fn set_click_through(&mut self, click_through: bool) {
+        let hwnd = match self.handle.borrow() {
+            Some(window) => window.hwnd,
+            None => return
+        };
+
+        let extended_style = unsafe { GetWindowLongPtrW(hwnd, GWL_EXSTYLE) } as DWORD;
+        let extended_style = match click_through {
+            true => extended_style | WS_EX_TRANSPARENT,
+            false => extended_style & !WS_EX_TRANSPARENT
+        };
+
+        unsafe { SetWindowLongPtrW(hwnd, GWL_EXSTYLE, extended_style as LONG_PTR) };
+        self.handle.extended_style = extended_style;
+    }
+
+    pub fn track(&mut self, query: TargetQuery) {
+        self.target = Some(TargetWindow::build(query));
+    }
+
+    pub fn untrack(&mut self) {
+        self.target = None;
+    }
+
+    pub fn update_tracking(&mut self) -> TrackingUpdate {
+        let hwnd = match self.handle.borrow() {
+            Some(window) => window.hwnd,
+            None => return TrackingUpdate::default()
+        };
+        let target = match self.target.as_mut() {
+            Some(target) => target,
+            None => return TrackingUpdate::default()
+        };
+
+        let snapshot = target.snapshot(hwnd);
+        let update = target.tracker.update(snapshot);
+
+        if let Some(rect) = update.moved {
+            unsafe { SetWindowPos(hwnd, HWND_TOPMOST, rect.x, rect.y, rect.width, rect.height, SWP_NOACTIVATE) };
+        }
 
-        let mut handle = WindowHandle::build(&class_handle);
-        handle.create();
+        if let Some(visible) = update.visibility {
+            unsafe { ShowWindow(hwnd, if visible { SW_SHOWNOACTIVATE } else { SW_HIDE }) };
+        }
 
-        let directx = match DirectX::create(&handle) {
+        if let Some([width, height]) = update.resized && let Some(directx) = self.directx.as_mut() {
+            directx.on_resize(width, height);
+        }
+
+        update
+    }
+
+    pub fn recover_device(&mut self) -> bool {
+        if let Some(directx) = self.directx.take_if(|directx| directx.is_lost()) {
+            self.recovery = Some(directx.teardown());
+        }
+        let state = match self.recovery.take() {
+            Some(state) => state,
+            None => return false
+        };
+
+        let adapter = match self.adapter {
+            AdapterPreference::Default => None,
+            preference => Adapter::select(preference)
+        };
+        match DirectX::restore(state, &self.handle, adapter.as_ref()) {
+            Ok(directx) => {
+                self.directx = Some(directx);
+                self.push_event(Event::DeviceRestored);
+                true
+            },
+            Err(state) => {
+                self.recovery = Some(state);
+                false
+            }
+        }
+    }
+
+    pub fn begin_frame(&mut self) -> Option<&mut Renderer> {
+        if let Some(directx) = self.directx.as_mut() {
+            let was_lost = directx.is_lost();
+            if let Some(reason) = directx.check_device() && !was_lost {
+                self.push_event(Event::DeviceLost(reason));
+            }
+        }
+
+        if self.directx.as_ref().is_none_or(|directx| directx.is_lost()) && !self.recover_device() {
+            return None;
+        }
+
+        let is_overlay = self.is_overlay();
+        let directx = self.directx.as_mut()?;
+        if !directx.setup() || directx.is_lost() {
+            return None;
+        }
+
+        let renderer = directx.get_renderer()?;
+        if is_overlay {
+            renderer.draw_background(Color::TRANSPARENT);
+        }
+
+        Some(renderer)
+    }
+
+    pub fn end_frame(&mut self) {
+        let directx = match self.directx.as_mut() {
             Some(directx) => directx,
-            None => return None
+            None => return
         };
 
-        Some(Self {
-            class_handle,
-            handle,
-            directx
-        })
+        if let Some(renderer) = directx.renderer.as_mut() {
+            renderer.flush();
+        }
+
+        let was_lost = directx.is_lost();
+        directx.present();
+
+        if !was_lost && let Some(reason) = directx.lost {
+            self.push_event(Event::DeviceLost(reason));
+        }
+    }
+
+    pub fn poll_events(&mut self) -> Vec<Event> {
+        let mut msg = unsafe { zeroed::<MSG>() };
+
+        while unsafe { PeekMessageW(&mut msg, null_mut(), 0, 0, PM_REMOVE) } != 0 {
+            if msg.message == WM_QUIT {
+                break;
+            }
+
+            unsafe {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+
+        self.take_events()
+    }
+
+    pub fn run<F: FnMut(Event) -> ControlFlow>(&mut self, mut callback: F) {
+        let mut msg = unsafe { zeroed::<MSG>() };
+
+        loop {
+            for event in self.take_events() {
+                if callback(event) == ControlFlow::Exit || event == Event::Destroyed {
+                    return;
+                }
+            }
+
+            if unsafe { GetMessageW(&mut msg, null_mut(), 0, 0) } <= 0 {
+                return;
+            }
+
+            unsafe {
+                TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        }
+    }
+
+    fn push_event(&self, event: Event) {
+        if let Ok(mut state) = self.events.lock() {
+            state.events.push(event);
+        }
+    }
+
+    fn take_events(&self) -> Vec<Event> {
+        match self.events.lock() {
+            Ok(mut state) => state.events.drain(..).collect(),
+            Err(_) => Vec::new()
+        }
     }
 }
 unsafe impl Send for Window {}
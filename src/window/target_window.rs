@@ -0,0 +1,108 @@
+use crate::dependencies::{
+    zeroed, BOOL, TRUE, FALSE, LPARAM, HWND, RECT, POINT, DWORD, EnumWindows, GetWindowTextW, GetClassNameW, GetWindowThreadProcessId, IsWindow, IsWindowVisible, IsIconic, GetClientRect, ClientToScreen, GetForegroundWindow, GetWindowRect
+};
+
+use crate::{ ScreenRect, TargetQuery, TargetSnapshot, OverlayTracker, OcclusionPolicy };
+
+struct WindowSearch<'a> {
+    query: &'a TargetQuery,
+    exclude: HWND,
+    hwnd: Option<HWND>
+}
+
+unsafe extern "system" fn enum_window(hwnd: HWND, lparam: LPARAM) -> BOOL {
+    let search = unsafe { &mut *(lparam as *mut WindowSearch) };
+
+    if hwnd == search.exclude || unsafe { IsWindowVisible(hwnd) } == FALSE {
+        return TRUE;
+    }
+
+    let mut process_id: DWORD = 0;
+    unsafe { GetWindowThreadProcessId(hwnd, &mut process_id) };
+
+    if search.query.matches(&window_title(hwnd), &window_class(hwnd), process_id) {
+        search.hwnd = Some(hwnd);
+        return FALSE;
+    }
+
+    TRUE
+}
+
+fn window_title(hwnd: HWND) -> String {
+    let mut buffer = [0u16; 512];
+    let length = unsafe { GetWindowTextW(hwnd, buffer.as_mut_ptr(), buffer.len() as i32) };
+
+    String::from_utf16_lossy(&buffer[..length.max(0) as usize])
+}
+
+fn window_class(hwnd: HWND) -> String {
+    let mut buffer = [0u16; 256];
+    let length = unsafe { GetClassNameW(hwnd, buffer.as_mut_ptr(), buffer.len() as i32) };
+
+    String::from_utf16_lossy(&buffer[..length.max(0) as usize])
+}
+
+fn window_rect(hwnd: HWND) -> ScreenRect {
+    let mut rect = unsafe { zeroed::<RECT>() };
+    unsafe { GetWindowRect(hwnd, &mut rect) };
+
+    ScreenRect::from_edges(rect.left, rect.top, rect.right, rect.bottom)
+}
+
+fn client_rect(hwnd: HWND) -> ScreenRect {
+    let mut rect = unsafe { zeroed::<RECT>() };
+    let mut origin = POINT { x: 0, y: 0 };
+    unsafe {
+        GetClientRect(hwnd, &mut rect);
+        ClientToScreen(hwnd, &mut origin);
+    }
+
+    ScreenRect::build(origin.x, origin.y, rect.right - rect.left, rect.bottom - rect.top)
+}
+
+#[derive(Clone)]
+pub struct TargetWindow {
+    pub query: TargetQuery,
+    pub hwnd: Option<HWND>,
+    pub tracker: OverlayTracker
+}
+impl TargetWindow {
+    pub fn build(query: TargetQuery) -> Self {
+        Self { query, hwnd: None, tracker: OverlayTracker::build(OcclusionPolicy::Foreground) }
+    }
+
+    pub fn find(query: &TargetQuery, exclude: HWND) -> Option<HWND> {
+        let mut search = WindowSearch { query, exclude, hwnd: None };
+        unsafe { EnumWindows(Some(enum_window), &mut search as *mut WindowSearch as LPARAM) };
+
+        search.hwnd
+    }
+
+    pub fn snapshot(&mut self, overlay: HWND) -> Option<TargetSnapshot> {
+        let hwnd = match self.hwnd {
+            Some(hwnd) if hwnd != overlay && unsafe { IsWindow(hwnd) } != FALSE => hwnd,
+            _ => {
+                self.hwnd = Self::find(&self.query, overlay);
+                match self.hwnd {
+                    Some(hwnd) => hwnd,
+                    None => return None
+                }
+            }
+        };
+
+        let foreground = unsafe { GetForegroundWindow() };
+        let foreground = match foreground == hwnd || foreground == overlay || foreground.is_null() {
+            true => None,
+            false => Some(window_rect(foreground))
+        };
+
+        Some(TargetSnapshot {
+            client: client_rect(hwnd),
+            minimized: unsafe { IsIconic(hwnd) } != FALSE,
+            visible: unsafe { IsWindowVisible(hwnd) } != FALSE,
+            foreground
+        })
+    }
+}
+unsafe impl Send for TargetWindow {}
+unsafe impl Sync for TargetWindow {}
\ No newline at end of file
@@ -0,0 +1,213 @@
+use crate::dependencies::{
+    c_int, null_mut, Arc, Mutex, DWORD, LPVOID, FALSE, HICON, HCURSOR, NULL, RECT, MARGINS, WS_OVERLAPPEDWINDOW, WS_POPUP, WS_VISIBLE, WS_EX_LAYERED, WS_EX_TRANSPARENT, WS_EX_TOPMOST, WS_EX_TOOLWINDOW, LWA_ALPHA, CW_USEDEFAULT, IDC_ARROW, AdjustWindowRectEx, LoadCursorW, SetLayeredWindowAttributes, DwmExtendFrameIntoClientArea
+};
+
+use crate::{ DirectX, Window, WindowClassHandle, WindowHandle, SwapchainConfig, DeviceConfig, Adapter, AdapterPreference, EventState };
+use crate::window::wide_string;
+
+#[derive(Clone)]
+pub struct WindowBuilder {
+    pub class_name: Vec<u16>,
+    pub title: Vec<u16>,
+    pub x: c_int,
+    pub y: c_int,
+    pub width: c_int,
+    pub height: c_int,
+    pub style: DWORD,
+    pub extended_style: DWORD,
+    pub cursor: HCURSOR,
+    pub icon: HICON,
+    pub small_icon: HICON,
+    pub swapchain_config: SwapchainConfig,
+    pub adapter: AdapterPreference,
+    pub device_config: DeviceConfig
+}
+impl WindowBuilder {
+    pub const DEFAULT_WIDTH: c_int = 800;
+    pub const DEFAULT_HEIGHT: c_int = 600;
+    pub const OVERLAY_STYLE: DWORD = WS_POPUP | WS_VISIBLE;
+    pub const OVERLAY_EXTENDED_STYLE: DWORD = WS_EX_LAYERED | WS_EX_TRANSPARENT | WS_EX_TOPMOST | WS_EX_TOOLWINDOW;
+
+    pub fn build(class_name: &str) -> Self {
+        Self {
+            class_name: wide_string(class_name),
+            title: wide_string(class_name),
+            x: CW_USEDEFAULT,
+            y: CW_USEDEFAULT,
+            width: Self::DEFAULT_WIDTH,
+            height: Self::DEFAULT_HEIGHT,
+            style: WS_OVERLAPPEDWINDOW,
+            extended_style: 0,
+            cursor: unsafe { LoadCursorW(null_mut(), IDC_ARROW) },
+            icon: NULL as HICON,
+            small_icon: NULL as HICON,
+            swapchain_config: SwapchainConfig::build(),
+            adapter: AdapterPreference::Default,
+            device_config: DeviceConfig::build()
+        }
+    }
+
+    pub fn overlay(class_name: &str) -> Self {
+        Self::build(class_name)
+            .position(0, 0)
+            .style(Self::OVERLAY_STYLE)
+            .extended_style(Self::OVERLAY_EXTENDED_STYLE)
+    }
+
+    pub fn is_layered(&self) -> bool {
+        self.extended_style & WS_EX_LAYERED != 0
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = wide_string(title);
+        self
+    }
+
+    pub fn class_name(mut self, class_name: &str) -> Self {
+        self.class_name = wide_string(class_name);
+        self
+    }
+
+    pub fn size(mut self, width: c_int, height: c_int) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    pub fn position(mut self, x: c_int, y: c_int) -> Self {
+        self.x = x;
+        self.y = y;
+        self
+    }
+
+    pub fn style(mut self, style: DWORD) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn extended_style(mut self, extended_style: DWORD) -> Self {
+        self.extended_style = extended_style;
+        self
+    }
+
+    pub fn cursor(mut self, cursor: HCURSOR) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    pub fn icon(mut self, icon: HICON) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    pub fn small_icon(mut self, small_icon: HICON) -> Self {
+        self.small_icon = small_icon;
+        self
+    }
+
+    pub fn swapchain(mut self, swapchain_config: SwapchainConfig) -> Self {
+        self.swapchain_config = swapchain_config;
+        self
+    }
+
+    pub fn adapter(mut self, adapter: AdapterPreference) -> Self {
+        self.adapter = adapter;
+        self
+    }
+
+    pub fn device(mut self, device_config: DeviceConfig) -> Self {
+        self.device_config = device_config;
+        self
+    }
+
+    pub fn window_rect(&self) -> RECT {
+        let mut rect = RECT {
+            left: 0,
+            top: 0,
+            right: self.width,
+            bottom: self.height
+        };
+
+        unsafe { AdjustWindowRectEx(&mut rect, self.style, FALSE, self.extended_style) };
+
+        rect
+    }
+
+    pub fn build_class(&self) -> WindowClassHandle {
+        let mut class_handle = WindowClassHandle::build("");
+        class_handle.name = self.class_name.clone();
+        class_handle.cursor = self.cursor;
+        class_handle.icon = self.icon;
+        class_handle.small_icon = self.small_icon;
+
+        class_handle
+    }
+
+    pub fn build_handle(&self, class_handle: &WindowClassHandle) -> WindowHandle {
+        let rect = self.window_rect();
+
+        let mut handle = WindowHandle::build(class_handle);
+        handle.title = self.title.clone();
+        handle.extended_style = self.extended_style;
+        handle.style = self.style;
+        handle.x = self.x;
+        handle.y = self.y;
+        handle.width = rect.right - rect.left;
+        handle.height = rect.bottom - rect.top;
+
+        handle
+    }
+
+    pub fn create(&self) -> Option<Window> {
+        let validation = match self.is_layered() {
+            true => self.swapchain_config.validate_layered(),
+            false => self.swapchain_config.validate()
+        };
+        if validation.is_err() {
+            return None;
+        }
+
+        let mut class_handle = self.build_class();
+        class_handle.register();
+
+        let events = Arc::new(Mutex::new(EventState::build()));
+
+        let mut handle = self.build_handle(&class_handle);
+        handle.param = Arc::as_ptr(&events) as LPVOID;
+        handle.create();
+
+        let hwnd = handle.borrow()?.hwnd;
+
+        if self.is_layered() {
+            let margins = MARGINS { cxLeftWidth: -1, cxRightWidth: -1, cyTopHeight: -1, cyBottomHeight: -1 };
+
+            unsafe {
+                SetLayeredWindowAttributes(hwnd, 0, 255, LWA_ALPHA);
+                DwmExtendFrameIntoClientArea(hwnd, &margins);
+            }
+        }
+
+        let adapter = match self.adapter {
+            AdapterPreference::Default => None,
+            preference => Adapter::select(preference)
+        };
+
+        let mut directx = match DirectX::create_with_device(&handle, self.swapchain_config, &self.device_config, adapter.as_ref()) {
+            Some(directx) => directx,
+            None => return None
+        };
+        directx.premultiplied_alpha = self.is_layered();
+
+        Some(Window {
+            directx: Some(directx),
+            recovery: None,
+            handle,
+            class_handle,
+            events,
+            target: None,
+            adapter: self.adapter
+        })
+    }
+}
+unsafe impl Send for WindowBuilder {}
+unsafe impl Sync for WindowBuilder {}
\ No newline at end of file
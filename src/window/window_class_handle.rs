@@ -1,8 +1,9 @@
 use crate::dependencies::{
-    c_int, OsStrExt, OsStr, UINT, WNDPROC, HINSTANCE, once, null, WNDCLASSEXW, CS_HREDRAW, CS_VREDRAW, RegisterClassExW, DefWindowProcW, UnregisterClassW, GetModuleHandleW, LPCWSTR, NULL, HICON, HCURSOR, HBRUSH
+    c_int, UINT, WNDPROC, HINSTANCE, null, WNDCLASSEXW, CS_HREDRAW, CS_VREDRAW, RegisterClassExW, UnregisterClassW, GetModuleHandleW, LPCWSTR, NULL, HICON, HCURSOR, HBRUSH
 };
 
-#[derive(Clone)]
+use crate::window::{ wide_string, window_proc };
+
 pub struct WindowClassHandle {
     pub size: UINT,
     pub style: UINT,
@@ -14,21 +15,16 @@ pub struct WindowClassHandle {
     pub cursor: HCURSOR,
     pub background: HBRUSH,
     pub menu_name: LPCWSTR,
-    pub name: LPCWSTR,
+    pub name: Vec<u16>,
     pub small_icon: HICON,
     pub wndclassexw: Option<WNDCLASSEXW>
 }
 impl WindowClassHandle {
-    pub fn build(name_str: &str) -> Self {
-        let name: Vec<u16> = OsStr::new(name_str)
-            .encode_wide()
-            .chain(once(0))
-            .collect();
-
+    pub fn build(name: &str) -> Self {
         Self {
             size: size_of::<WNDCLASSEXW>() as u32,
             style: CS_HREDRAW | CS_VREDRAW,
-            process: Some(DefWindowProcW),
+            process: Some(window_proc),
             class_extra: 0,
             window_extra: 0,
             instance: unsafe { GetModuleHandleW(null()) },
@@ -36,7 +32,7 @@ impl WindowClassHandle {
             cursor: NULL as HCURSOR,
             background: NULL as HBRUSH,
             menu_name: NULL as LPCWSTR,
-            name: name.as_ptr() as LPCWSTR,
+            name: wide_string(name),
             small_icon: NULL as HICON,
             wndclassexw: None
         }
@@ -56,16 +52,25 @@ impl WindowClassHandle {
             hCursor: self.cursor,
             hbrBackground: self.background,
             lpszMenuName: self.menu_name,
-            lpszClassName: self.name,
+            lpszClassName: self.name.as_ptr(),
             hIconSm: self.small_icon
         };
 
-        unsafe { RegisterClassExW(&wndclassexw) };
-        self.wndclassexw = Some(wndclassexw);
+        if unsafe { RegisterClassExW(&wndclassexw) } != 0 {
+            self.wndclassexw = Some(wndclassexw);
+        }
     }
 
-    pub fn unregister(&self) {
-        unsafe { UnregisterClassW(self.name, self.instance) };
+    pub fn unregister(&mut self) {
+        unsafe { UnregisterClassW(self.name.as_ptr(), self.instance) };
+        self.wndclassexw = None;
+    }
+}
+impl Drop for WindowClassHandle {
+    fn drop(&mut self) {
+        if self.wndclassexw.is_some() {
+            self.unregister();
+        }
     }
 }
 unsafe impl Send for WindowClassHandle {}
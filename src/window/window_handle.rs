@@ -1,14 +1,13 @@
 use crate::dependencies::{
-    c_int, null_mut, zeroed, WS_OVERLAPPEDWINDOW, CreateWindowExW, DestroyWindow, GetClientRect, DWORD, HINSTANCE, LPVOID, LPCWSTR, NULL, HWND, HMENU, RECT
+    c_int, null_mut, WS_OVERLAPPEDWINDOW, CreateWindowExW, DestroyWindow, DWORD, HINSTANCE, LPVOID, NULL, HWND, HMENU, RECT
 };
 
-use crate::{ WindowClassHandle};
+use crate::{ WindowClassHandle, WindowRef };
 
-#[derive(Clone)]
 pub struct WindowHandle {
     pub extended_style: DWORD,
-    pub class_handle_name: LPCWSTR,
-    pub name: LPCWSTR,
+    pub class_name: Vec<u16>,
+    pub title: Vec<u16>,
     pub style: DWORD,
     pub x: c_int,
     pub y: c_int,
@@ -24,8 +23,8 @@ impl WindowHandle {
     pub fn build(class_handle: &WindowClassHandle) -> Self {
         Self {
             extended_style: 0,
-            class_handle_name: class_handle.name,
-            name: class_handle.name,
+            class_name: class_handle.name.clone(),
+            title: class_handle.name.clone(),
             style: WS_OVERLAPPEDWINDOW,
             x: 0,
             y: 0,
@@ -42,8 +41,8 @@ impl WindowHandle {
     pub fn create(&mut self) {
         self.hwnd = Some(unsafe { CreateWindowExW(
             self.extended_style,
-            self.class_handle_name,
-            self.name,
+            self.class_name.as_ptr(),
+            self.title.as_ptr(),
             self.style,
             self.x,
             self.y,
@@ -56,21 +55,26 @@ impl WindowHandle {
         ) });
     }
 
-    pub fn destroy(&self) {
-        if !self.hwnd.is_none() {
-            unsafe { DestroyWindow(self.hwnd.unwrap()) };
+    pub fn destroy(&mut self) {
+        if let Some(hwnd) = self.hwnd.take() && !hwnd.is_null() {
+            unsafe { DestroyWindow(hwnd) };
         }
     }
 
-    pub fn get_rect(&self) -> Option<RECT> {
-        if !self.hwnd.is_none() {
-            let mut rect =  unsafe { zeroed::<RECT>() };
-            unsafe { GetClientRect(self.hwnd.unwrap(), &mut rect) };
-
-            return Some(rect);
+    pub fn borrow(&self) -> Option<WindowRef<'_>> {
+        match self.hwnd {
+            Some(hwnd) if !hwnd.is_null() => Some(WindowRef::build(hwnd)),
+            _ => None
         }
+    }
 
-        None
+    pub fn get_rect(&self) -> Option<RECT> {
+        self.borrow().map(|window| window.get_rect())
+    }
+}
+impl Drop for WindowHandle {
+    fn drop(&mut self) {
+        self.destroy();
     }
 }
 unsafe impl Send for WindowHandle {}
@@ -0,0 +1,50 @@
+use crate::dependencies::{
+    null_mut, Mutex, HWND, UINT, WPARAM, LPARAM, LRESULT, LONG_PTR, RECT, CREATESTRUCTW, GWLP_USERDATA, WM_NCCREATE, WM_NCDESTROY, WM_CLOSE, WM_DPICHANGED, SWP_NOZORDER, SWP_NOACTIVATE, DefWindowProcW, GetWindowLongPtrW, SetWindowLongPtrW, SetWindowPos
+};
+
+use crate::{ EventState };
+
+pub type EventQueue = Mutex<EventState>;
+
+/// # Safety
+/// Must only be installed as a window procedure. The creation parameter of the window must be null or point to an
+/// `EventQueue` that outlives the window.
+pub unsafe extern "system" fn window_proc(hwnd: HWND, msg: UINT, wparam: WPARAM, lparam: LPARAM) -> LRESULT {
+    if msg == WM_NCCREATE {
+        let create = unsafe { &*(lparam as *const CREATESTRUCTW) };
+        unsafe { SetWindowLongPtrW(hwnd, GWLP_USERDATA, create.lpCreateParams as LONG_PTR) };
+    }
+
+    let queue = unsafe { GetWindowLongPtrW(hwnd, GWLP_USERDATA) } as *const EventQueue;
+    if queue.is_null() {
+        return unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) };
+    }
+
+    if let Ok(mut state) = unsafe { &*queue }.lock() {
+        state.translate(msg, wparam, lparam);
+    }
+
+    match msg {
+        WM_CLOSE => return 0,
+        WM_DPICHANGED => {
+            let rect = unsafe { &*(lparam as *const RECT) };
+            unsafe { SetWindowPos(
+                hwnd,
+                null_mut(),
+                rect.left,
+                rect.top,
+                rect.right - rect.left,
+                rect.bottom - rect.top,
+                SWP_NOZORDER | SWP_NOACTIVATE
+            ) };
+
+            return 0;
+        },
+        WM_NCDESTROY => {
+            unsafe { SetWindowLongPtrW(hwnd, GWLP_USERDATA, 0) };
+        },
+        _ => ()
+    }
+
+    unsafe { DefWindowProcW(hwnd, msg, wparam, lparam) }
+}
\ No newline at end of file
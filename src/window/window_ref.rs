@@ -0,0 +1,25 @@
+use crate::dependencies::{
+    zeroed, PhantomData, GetClientRect, HWND, RECT
+};
+
+use crate::{ WindowHandle };
+
+#[derive(Clone, Copy)]
+pub struct WindowRef<'a> {
+    pub hwnd: HWND,
+    marker: PhantomData<&'a WindowHandle>
+}
+impl WindowRef<'_> {
+    pub fn build(hwnd: HWND) -> Self {
+        Self { hwnd, marker: PhantomData }
+    }
+
+    pub fn get_rect(&self) -> RECT {
+        let mut rect = unsafe { zeroed::<RECT>() };
+        unsafe { GetClientRect(self.hwnd, &mut rect) };
+
+        rect
+    }
+}
+unsafe impl Send for WindowRef<'_> {}
+unsafe impl Sync for WindowRef<'_> {}
\ No newline at end of file